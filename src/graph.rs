@@ -0,0 +1,177 @@
+use std::{collections::{HashMap, VecDeque}, error::Error, future::Future};
+
+use crate::{Client, parse::ArtistSearchResult};
+
+/// Artists connected by their "Fans might also like" sections
+#[derive(Debug, Clone, Default)]
+pub struct ArtistGraph {
+    pub nodes: Vec<ArtistNode>,
+    /// Pairs of indices into `nodes`, pointing from an artist to one of their related artists
+    pub edges: Vec<(usize, usize)>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArtistNode {
+    pub name: String,
+    pub browse_id: String,
+    /// Number of steps from the seed artist
+    pub depth: usize,
+}
+
+impl Client {
+    /// Walk related artists breadth-first, starting at the artist with the `seed` browse id
+    ///
+    /// Artists further than `depth` steps from the seed are not requested
+    /// and no more than `limit` artists are added to the graph.
+    /// Only a failure to load the seed artist is returned as an error,
+    /// artists that fail to load later on are kept in the graph without their outgoing edges.
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_artists("Rammstein").await.unwrap();
+    ///     let graph = client.related_artist_graph(&results[0].browse_id, 2, 50).await.unwrap();
+    ///     for (from, to) in graph.edges {
+    ///         println!("{} -> {}", graph.nodes[from].name, graph.nodes[to].name);
+    ///     }
+    /// }
+    /// ```
+    pub async fn related_artist_graph(&self, seed: &str, depth: usize, limit: usize) -> Result<ArtistGraph, Box<dyn Error>> {
+        walk(seed, depth, limit, |browse_id| async move {
+            let artist = self.get_artist(&browse_id).await?;
+            Ok((artist.name, artist.related))
+        }).await
+    }
+}
+
+/// Breadth-first walk behind [`Client::related_artist_graph`],
+/// with `fetch` returning the name and related artists of a browse id
+async fn walk<F, Fut>(seed: &str, depth: usize, limit: usize, fetch: F) -> Result<ArtistGraph, Box<dyn Error>>
+where
+    F: Fn(String) -> Fut,
+    Fut: Future<Output = Result<(String, Vec<ArtistSearchResult>), Box<dyn Error>>>,
+{
+    let mut graph = ArtistGraph::default();
+    if limit == 0 {
+        return Ok(graph);
+    }
+
+    let (name, seed_related) = fetch(seed.to_string()).await?;
+    graph.nodes.push(ArtistNode {
+        name,
+        browse_id: seed.to_string(),
+        depth: 0,
+    });
+    let mut indices = HashMap::from([(seed.to_string(), 0)]);
+    let mut queue = VecDeque::from([(0, Some(seed_related))]);
+
+    while let Some((from, related)) = queue.pop_front() {
+        let node = &graph.nodes[from];
+        if node.depth >= depth {
+            continue;
+        }
+        let node_depth = node.depth;
+        let related = match related {
+            Some(it) => it,
+            None => match fetch(node.browse_id.clone()).await {
+                Ok((_, related)) => related,
+                Err(_) => continue,
+            },
+        };
+
+        for related in related {
+            let to = match indices.get(&related.browse_id) {
+                Some(&it) => it,
+                None if graph.nodes.len() < limit => {
+                    graph.nodes.push(ArtistNode {
+                        name: related.name,
+                        browse_id: related.browse_id.clone(),
+                        depth: node_depth + 1,
+                    });
+                    let index = graph.nodes.len() - 1;
+                    indices.insert(related.browse_id, index);
+                    queue.push_back((index, None));
+                    index
+                },
+                None => continue,
+            };
+            graph.edges.push((from, to));
+        }
+    }
+
+    Ok(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashMap, error::Error};
+
+    use crate::parse::ArtistSearchResult;
+
+    use super::{walk, ArtistGraph};
+
+    async fn fake_walk(related: &[(&str, &[&str])], seed: &str, depth: usize, limit: usize) -> Result<ArtistGraph, Box<dyn Error>> {
+        let related: HashMap<_, _> = related.iter().cloned().collect();
+        walk(seed, depth, limit, |browse_id| {
+            let res = match related.get(browse_id.as_str()) {
+                Some(ids) => Ok((browse_id.to_uppercase(), ids.iter().map(|id| ArtistSearchResult {
+                    name: id.to_uppercase(),
+                    subs: String::new(),
+                    browse_id: id.to_string(),
+                }).collect())),
+                None => Err(format!("no artist {browse_id}").into()),
+            };
+            async move { res }
+        }).await
+    }
+
+    fn ids(graph: &ArtistGraph) -> Vec<(&str, usize)> {
+        graph.nodes.iter().map(|node| (node.browse_id.as_str(), node.depth)).collect()
+    }
+
+    const RELATED: &[(&str, &[&str])] = &[
+        ("a", &["b", "c"]),
+        ("b", &["a", "c", "d"]),
+        ("c", &["e"]),
+        ("d", &[]),
+        ("e", &["a"]),
+    ];
+
+    #[tokio::test]
+    async fn depth() {
+        let graph = fake_walk(RELATED, "a", 1, 10).await.unwrap();
+        assert_eq!(ids(&graph), [("a", 0), ("b", 1), ("c", 1)]);
+        assert_eq!(graph.edges, [(0, 1), (0, 2)]);
+        assert_eq!(graph.nodes[0].name, "A");
+
+        let graph = fake_walk(RELATED, "a", 0, 10).await.unwrap();
+        assert_eq!(ids(&graph), [("a", 0)]);
+        assert!(graph.edges.is_empty());
+    }
+
+    #[tokio::test]
+    async fn limit() {
+        let graph = fake_walk(RELATED, "a", 5, 3).await.unwrap();
+        assert_eq!(ids(&graph), [("a", 0), ("b", 1), ("c", 1)]);
+        assert_eq!(graph.edges, [(0, 1), (0, 2), (1, 0), (1, 2)]);
+
+        assert!(fake_walk(RELATED, "a", 5, 0).await.unwrap().nodes.is_empty());
+    }
+
+    #[tokio::test]
+    async fn dedup_and_known_edges() {
+        let graph = fake_walk(RELATED, "a", 5, 10).await.unwrap();
+        assert_eq!(ids(&graph), [("a", 0), ("b", 1), ("c", 1), ("d", 2), ("e", 2)]);
+        assert_eq!(graph.edges, [(0, 1), (0, 2), (1, 0), (1, 2), (1, 3), (2, 4), (4, 0)]);
+    }
+
+    #[tokio::test]
+    async fn failed_nodes() {
+        let graph = fake_walk(&[("a", &["b", "x"]), ("b", &["x"])], "a", 5, 10).await.unwrap();
+        assert_eq!(ids(&graph), [("a", 0), ("b", 1), ("x", 1)]);
+        assert_eq!(graph.edges, [(0, 1), (0, 2), (1, 2)]);
+
+        assert!(fake_walk(RELATED, "x", 5, 10).await.is_err());
+    }
+}
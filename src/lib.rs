@@ -1,16 +1,28 @@
 use std::{collections::HashMap, error::Error};
+use futures::Stream;
+use reqwest::Url;
 use serde_json::{Value, Map, json};
 
 mod config;
+mod graph;
 mod parse;
+mod search;
+mod stream;
 
 use config::YoutubeConfig;
-use crate::requests::{create_api_request, endpoint_context};
+use crate::requests::{create_api_request, create_continuation_request, create_mobile_api_request, create_page_request, endpoint_context, watch_context};
+use crate::stream::paginate;
+pub use crate::graph::*;
 pub use crate::parse::*;
+pub use crate::search::*;
 
 mod requests;
 
 pub(crate) const BASE_URL: &str = "https://music.youtube.com/";
+/// `next` params of a radio, as used by "Start radio" buttons
+const RADIO_PARAMS: &str = "wAEB";
+/// `next` params of shuffling a playlist
+const SHUFFLE_PARAMS: &str = "wAEB8gECKAE%3D";
 
 // TODO: add much more tests
 #[tokio::main]
@@ -48,15 +60,142 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub async fn get_artist(self: &Self, browse_id: &str) -> Result<Artist, Box<dyn Error>> {
+    pub async fn get_artist(&self, browse_id: &str) -> Result<Artist, Box<dyn Error>> {
         let res = create_api_request(
             &self.config, "browse", endpoint_context("ARTIST", browse_id)
         ).await?;
 
         // std::fs::write("res.art.json", res.to_string()).unwrap();
-        Ok(Artist::parse(res)?)
+        Ok(Artist::parse(res, browse_id)?)
     }
     
+    /// Request all songs of an artist by the browse id of their songs playlist
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_artists("Rammstein").await.unwrap();
+    ///     let artist = client.get_artist(&results[0].browse_id).await.unwrap();
+    ///     if let Some(browse_id) = artist.top_songs_browse_id {
+    ///         dbg!(client.get_artist_songs(&browse_id).await.unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_artist_songs(&self, browse_id: &str) -> Result<Vec<ArtistSong>, Box<dyn Error>> {
+        self.browse_all("PLAYLIST", browse_id, None, ArtistSong::parse_playlist).await
+    }
+
+    /// Request all music videos of an artist
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_artists("Rammstein").await.unwrap();
+    ///     let artist = client.get_artist(&results[0].browse_id).await.unwrap();
+    ///     dbg!(client.get_artist_videos(&artist).await.unwrap());
+    /// }
+    /// ```
+    pub async fn get_artist_videos(&self, artist: &Artist) -> Result<Vec<ArtistVideo>, Box<dyn Error>> {
+        match &artist.videos_browse_id {
+            Some(browse_id) => self.browse_all("PLAYLIST", browse_id, None, ArtistVideo::parse_playlist).await,
+            None => Ok(artist.videos.clone()),
+        }
+    }
+
+    /// Request a complete part of an artist's discography
+    ///
+    /// ```no_run
+    /// use youtube_music::DiscographyKind;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_artists("Rammstein").await.unwrap();
+    ///     let albums = client.get_discography(&results[0].browse_id, DiscographyKind::Albums).await.unwrap();
+    ///     dbg!(albums.len());
+    /// }
+    /// ```
+    pub async fn get_discography(&self, artist_browse_id: &str, kind: DiscographyKind) -> Result<Vec<Product>, Box<dyn Error>> {
+        let artist = self.get_artist(artist_browse_id).await?;
+        match kind {
+            DiscographyKind::Albums => artist.full_albums(self).await,
+            DiscographyKind::Singles => artist.full_singles(self).await,
+        }
+    }
+
+    /// Request a part of an artist's discography page by page, only requesting the next page once the current one is consumed
+    ///
+    /// ```no_run
+    /// use futures::StreamExt;
+    /// use youtube_music::DiscographyKind;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_artists("Rammstein").await.unwrap();
+    ///     let singles = client.discography_stream(&results[0].browse_id, DiscographyKind::Singles);
+    ///     let first: Vec<_> = singles.take(5).collect().await;
+    ///     dbg!(first);
+    /// }
+    /// ```
+    pub fn discography_stream<'a>(&'a self, artist_browse_id: &'a str, kind: DiscographyKind) -> impl Stream<Item = Result<Product, Box<dyn Error>>> + 'a {
+        paginate(move |token| async move {
+            if let Some(token) = token {
+                let res = create_continuation_request(&self.config, "browse", &token.0).await?;
+                return Ok(Product::parse_discography_page(res)?);
+            }
+            let artist = self.get_artist(artist_browse_id).await?;
+            let (endpoint, shown) = match kind {
+                DiscographyKind::Albums => (artist.albums_endpoint, artist.albums),
+                DiscographyKind::Singles => (artist.singles_endpoint, artist.singles),
+            };
+            Ok(match endpoint {
+                Some(endpoint) => {
+                    let res = self.browse_page("ARTIST_DISCOGRAPHY", &endpoint.browse_id, endpoint.params.as_deref()).await?;
+                    Product::parse_discography_page(res)?
+                },
+                None => (shown, None),
+            })
+        })
+    }
+
+    pub(crate) async fn browse_discography(&self, endpoint: &BrowseEndpoint) -> Result<Vec<Product>, Box<dyn Error>> {
+        self.browse_all(
+            "ARTIST_DISCOGRAPHY", &endpoint.browse_id, endpoint.params.as_deref(), Product::parse_discography_page
+        ).await
+    }
+
+    /// Browse a page and all of its continuations
+    async fn browse_all<T>(
+        &self,
+        page_type: &str,
+        browse_id: &str,
+        params: Option<&str>,
+        parse: fn(Value) -> Result<Continued<T>, ResponseParseError>,
+    ) -> Result<Vec<T>, Box<dyn Error>> {
+        let res = self.browse_page(page_type, browse_id, params).await?;
+        let (mut items, mut next) = parse(res)?;
+
+        while let Some(token) = next {
+            let res = create_continuation_request(&self.config, "browse", &token.0).await?;
+            let (mut page, page_next) = parse(res)?;
+            items.append(&mut page);
+            next = page_next;
+        }
+        Ok(items)
+    }
+
+    /// Request the first page of a browse endpoint
+    async fn browse_page(&self, page_type: &str, browse_id: &str, params: Option<&str>) -> Result<Value, reqwest::Error> {
+        let mut body_vars = endpoint_context(page_type, browse_id);
+        if let Some(params) = params {
+            body_vars.insert("params".to_string(), Value::String(params.to_string()));
+        }
+        create_api_request(&self.config, "browse", body_vars).await
+    }
+
     /// Search an artist by their channel's name
     ///
     /// ```no_run
@@ -72,13 +211,439 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub async fn get_album(self: &Self, browse_id: &str) -> Result<Album, Box<dyn Error>> {
+    pub async fn get_album(&self, browse_id: &str) -> Result<Album, Box<dyn Error>> {
         let res = create_api_request(
             &self.config, "browse", endpoint_context("ALBUM", browse_id)
         ).await?;
 
         // std::fs::write("res.alb.json", res.to_string()).unwrap();
-        Ok(Album::parse(res)?)
+        Ok(Album::parse(res, browse_id)?)
+    }
+
+    /// Request an album by the id of its playlist, starting with `OLAK5uy_`
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     dbg!(client.get_album_by_playlist_id("OLAK5uy_nl5CYbyn0jhWQiRJ17yvqRDN96eTx7sWQ").await.unwrap());
+    /// }
+    /// ```
+    pub async fn get_album_by_playlist_id(&self, playlist_id: &str) -> Result<Album, Box<dyn Error>> {
+        let browse_id = self.get_album_browse_id(playlist_id).await?;
+        self.get_album(&browse_id).await
+    }
+
+    /// Find an album's browse id by the id of its playlist, starting with `OLAK5uy_`
+    pub async fn get_album_browse_id(&self, playlist_id: &str) -> Result<String, Box<dyn Error>> {
+        let mut url = Url::parse(BASE_URL).expect("invalid base url");
+        url.set_path("playlist");
+        url.query_pairs_mut().append_pair("list", playlist_id);
+        let html = create_page_request(url).await?;
+
+        Ok(album_browse_id_from_html(&html).ok_or(
+            ResponseParseError::MissingValue(format!("album browse id of playlist {playlist_id}"))
+        )?)
+    }
+
+    /// Request a playlist with all of its tracks, following continuations until the end of the playlist
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let playlist = client.get_playlist("RDCLAK5uy_nP1U38z5TIWBgTTiXDBgO_3DaHSaotjbc").await.unwrap();
+    ///     dbg!(playlist.tracks.len());
+    /// }
+    /// ```
+    pub async fn get_playlist(&self, playlist_id: &str) -> Result<Playlist, Box<dyn Error>> {
+        let (mut playlist, mut next) = self.get_playlist_page(playlist_id).await?;
+
+        while let Some(token) = next {
+            let res = create_continuation_request(&self.config, "browse", &token.0).await?;
+            let (mut page, page_next) = PlaylistItem::parse_continuation(res)?;
+            playlist.tracks.append(&mut page);
+            next = page_next;
+        }
+        Ok(playlist)
+    }
+
+    /// Request a playlist's details with the first page of its tracks
+    async fn get_playlist_page(&self, playlist_id: &str) -> Result<(Playlist, Option<ContinuationToken>), Box<dyn Error>> {
+        let playlist_id = playlist_id.strip_prefix("VL").unwrap_or(playlist_id);
+        let res = create_api_request(
+            &self.config, "browse", endpoint_context("PLAYLIST", &format!("VL{playlist_id}"))
+        ).await?;
+        Ok(Playlist::parse(res, playlist_id)?)
+    }
+
+    /// Request a playlist's tracks, lazily requesting further pages as the stream is consumed
+    ///
+    /// ```no_run
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let tracks: Vec<_> = client.playlist_tracks_stream("RDCLAK5uy_nP1U38z5TIWBgTTiXDBgO_3DaHSaotjbc")
+    ///         .take(300)
+    ///         .try_collect()
+    ///         .await
+    ///         .unwrap();
+    ///     dbg!(tracks.len());
+    /// }
+    /// ```
+    pub fn playlist_tracks_stream<'a>(&'a self, playlist_id: &'a str) -> impl Stream<Item = Result<PlaylistItem, Box<dyn Error>>> + 'a {
+        paginate(move |token| async move {
+            Ok(match token {
+                None => {
+                    let (playlist, next) = self.get_playlist_page(playlist_id).await?;
+                    (playlist.tracks, next)
+                },
+                Some(token) => {
+                    let res = create_continuation_request(&self.config, "browse", &token.0).await?;
+                    PlaylistItem::parse_continuation(res)?
+                },
+            })
+        })
+    }
+
+    /// Request the up-next queue Youtube Music's player would play after a video, along with the browse ids of its lyrics and related content
+    ///
+    /// Without a playlist, the queue is the video's radio.
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let queue = client.get_watch_playlist("KUZ7jG7BKE8", None).await.unwrap();
+    ///     for track in queue.tracks {
+    ///         println!("{} - {:?}", track.title, track.artists);
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_watch_playlist(&self, video_id: &str, playlist_id: Option<&str>) -> Result<WatchPlaylist, Box<dyn Error>> {
+        let playlist_id = playlist_id.map(str::to_string).unwrap_or_else(|| format!("RDAMVM{video_id}"));
+        let res = create_api_request(
+            &self.config, "next", watch_context(Some(video_id), Some(&playlist_id), None)
+        ).await?;
+        Ok(WatchPlaylist::parse(res)?)
+    }
+
+    /// Request more of a queue, returning its next items with the token for the rest
+    pub async fn watch_next(&self, token: &ContinuationToken) -> Result<(Vec<QueueItem>, Option<ContinuationToken>), Box<dyn Error>> {
+        let res = create_continuation_request(&self.config, "next", &token.0).await?;
+        Ok(QueueItem::parse_continuation(res)?)
+    }
+
+    /// Request the lyrics of a song, with their timings if Youtube has them
+    ///
+    /// Returns `None` for songs without lyrics.
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     if let Some(lyrics) = client.get_lyrics("KUZ7jG7BKE8").await.unwrap() {
+    ///         println!("{}\n\n{}", lyrics.text, lyrics.source.unwrap_or_default());
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_lyrics(&self, video_id: &str) -> Result<Option<Lyrics>, Box<dyn Error>> {
+        let Some(browse_id) = self.get_watch_playlist(video_id, None).await?.lyrics_browse_id else {
+            return Ok(None);
+        };
+        let res = create_mobile_api_request(
+            &self.config, "browse", endpoint_context("TRACK_LYRICS", &browse_id)
+        ).await?;
+        Ok(Some(Lyrics::parse(res)?))
+    }
+
+    /// Request the details of a song or video from the player, such as its playability and formats
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let song = client.get_song("KUZ7jG7BKE8").await.unwrap();
+    ///     println!("{:?} by {:?}, {:?}", song.title, song.author, song.playability);
+    /// }
+    /// ```
+    pub async fn get_song(&self, video_id: &str) -> Result<SongDetails, Box<dyn Error>> {
+        let body_vars = json!({
+            "videoId": video_id,
+        }).as_object().unwrap().to_owned();
+        let res = create_api_request(&self.config, "player", body_vars).await?;
+        Ok(SongDetails::parse(res, video_id)?)
+    }
+
+    /// Request the content related to a song, such as similar songs, playlists and artists
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     for section in client.get_song_related("KUZ7jG7BKE8").await.unwrap() {
+    ///         dbg!(section);
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_song_related(&self, video_id: &str) -> Result<Vec<RelatedSection>, Box<dyn Error>> {
+        let browse_id = self.get_watch_playlist(video_id, None).await?.related_browse_id.ok_or(
+            ResponseParseError::MissingValue(format!("related content browse id of song {video_id}"))
+        )?;
+        let res = create_api_request(
+            &self.config, "browse", endpoint_context("TRACK_RELATED", &browse_id)
+        ).await?;
+        Ok(RelatedSection::parse_all(res)?)
+    }
+
+    /// Request the shelves of the home feed, following continuations until `limit` shelves are collected
+    ///
+    /// Without signing in, the feed consists of content trending in the client's region.
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     for shelf in client.get_home(10).await.unwrap() {
+    ///         println!("{}: {} items", shelf.title, shelf.items.len());
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_home(&self, limit: usize) -> Result<Vec<HomeShelf>, Box<dyn Error>> {
+        let body_vars = json!({
+            "browseId": "FEmusic_home",
+        }).as_object().unwrap().to_owned();
+        let res = create_api_request(&self.config, "browse", body_vars).await?;
+        let (mut shelves, mut next) = HomeShelf::parse_page(res)?;
+
+        while shelves.len() < limit {
+            let Some(token) = next else {
+                break;
+            };
+            let res = create_continuation_request(&self.config, "browse", &token.0).await?;
+            let (mut page, page_next) = HomeShelf::parse_page(res)?;
+            shelves.append(&mut page);
+            next = page_next;
+        }
+        shelves.truncate(limit);
+        Ok(shelves)
+    }
+
+    /// Request the shelves of new albums, singles and music videos
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     for shelf in client.get_new_releases().await.unwrap() {
+    ///         println!("{}: {} items", shelf.title, shelf.items.len());
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_new_releases(&self) -> Result<Vec<HomeShelf>, Box<dyn Error>> {
+        let body_vars = json!({
+            "browseId": "FEmusic_new_releases",
+        }).as_object().unwrap().to_owned();
+        let res = create_api_request(&self.config, "browse", body_vars).await?;
+        Ok(HomeShelf::parse_page(res)?.0)
+    }
+
+    /// Request the charts of a country by its code, such as "US", or the global charts without one
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let charts = client.get_charts(Some("CZ")).await.unwrap();
+    ///     for entry in charts.artists {
+    ///         println!("{:?} {:?} {}", entry.rank, entry.trend, entry.item.name);
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_charts(&self, country: Option<&str>) -> Result<Charts, Box<dyn Error>> {
+        let mut body_vars = json!({
+            "browseId": "FEmusic_charts",
+        }).as_object().unwrap().to_owned();
+        if let Some(country) = country {
+            body_vars.insert("formData".to_string(), json!({"selectedValues": [country]}));
+        }
+        let res = create_api_request(&self.config, "browse", body_vars).await?;
+        Ok(Charts::parse(res)?)
+    }
+
+    /// Request the categories of the moods & genres page
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let sections = client.get_mood_categories().await.unwrap();
+    ///     if let Some(category) = sections.iter().flat_map(|section| &section.categories).next() {
+    ///         dbg!(client.get_mood_playlists(&category.params).await.unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub async fn get_mood_categories(&self) -> Result<Vec<MoodSection>, Box<dyn Error>> {
+        let body_vars = json!({
+            "browseId": "FEmusic_moods_and_genres",
+        }).as_object().unwrap().to_owned();
+        let res = create_api_request(&self.config, "browse", body_vars).await?;
+        Ok(MoodSection::parse_all(res)?)
+    }
+
+    /// Request the playlists of a mood or genre by the params of its [`MoodCategory`]
+    pub async fn get_mood_playlists(&self, params: &str) -> Result<Vec<PlaylistRef>, Box<dyn Error>> {
+        let body_vars = json!({
+            "browseId": "FEmusic_moods_and_genres_category",
+            "params": params,
+        }).as_object().unwrap().to_owned();
+        let res = create_api_request(&self.config, "browse", body_vars).await?;
+        Ok(mood_playlists(res)?)
+    }
+
+    /// Search podcasts by their title
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_podcasts("metal").await.unwrap();
+    ///     if let Some(first_result) = results.items.into_iter().next() {
+    ///         let podcast = first_result.request(&client).await.unwrap();
+    ///         if let Some(episode) = podcast.episodes.first() {
+    ///             dbg!(client.get_episode(&episode.video_id).await.unwrap());
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub async fn search_podcasts(&self, query: &str) -> Result<SearchPage<PodcastRef>, Box<dyn Error>> {
+        let res = self.search_with(query, &SearchFilter::new(SearchCategory::Podcasts)).await?;
+        Ok(PodcastRef::parse_search(res)?)
+    }
+
+    /// Request the next page of podcast search results
+    pub async fn search_podcasts_next(&self, token: &ContinuationToken) -> Result<SearchPage<PodcastRef>, Box<dyn Error>> {
+        let res = create_continuation_request(&self.config, "search", &token.0).await?;
+        Ok(PodcastRef::parse_search_continuation(res)?)
+    }
+
+    /// Request a podcast with all of its episodes, following continuations until the oldest one
+    pub async fn get_podcast(&self, browse_id: &str) -> Result<Podcast, Box<dyn Error>> {
+        let res = create_api_request(
+            &self.config, "browse", endpoint_context("PODCAST_SHOW_DETAIL_PAGE", browse_id)
+        ).await?;
+        let (mut podcast, mut next) = Podcast::parse(res, browse_id)?;
+
+        while let Some(token) = next {
+            let res = create_continuation_request(&self.config, "browse", &token.0).await?;
+            let (mut page, page_next) = Episode::parse_continuation(res)?;
+            podcast.episodes.append(&mut page);
+            next = page_next;
+        }
+        Ok(podcast)
+    }
+
+    /// Request a podcast episode by its video id, with its description, date and duration
+    pub async fn get_episode(&self, video_id: &str) -> Result<Episode, Box<dyn Error>> {
+        let res = create_api_request(
+            &self.config, "browse", endpoint_context("NON_MUSIC_AUDIO_TRACK_PAGE", &format!("MPED{video_id}"))
+        ).await?;
+        Ok(Episode::parse_page_header(res, video_id)?)
+    }
+
+    /// Play a radio based on a song, lazily requesting more of it as the stream is consumed
+    ///
+    /// Radios never run out, so the stream should be limited by the consumer.
+    ///
+    /// ```no_run
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let radio: Vec<_> = client.radio_for_song("KUZ7jG7BKE8")
+    ///         .take(100)
+    ///         .try_collect()
+    ///         .await
+    ///         .unwrap();
+    ///     dbg!(radio);
+    /// }
+    /// ```
+    pub fn radio_for_song<'a>(&'a self, video_id: &str) -> impl Stream<Item = Result<QueueItem, Box<dyn Error>>> + 'a {
+        let playlist_id = format!("RDAMVM{video_id}");
+        self.queue_stream(Ok(watch_context(Some(video_id), Some(&playlist_id), Some(RADIO_PARAMS))))
+    }
+
+    /// Play the radio of an artist, as started from their page
+    ///
+    /// ```no_run
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let artist = client.get_artist("UCs6GGpd9zvsYghuYe0VDFUQ").await.unwrap();
+    ///     let radio: Vec<_> = client.radio_for_artist(&artist)
+    ///         .take(100)
+    ///         .try_collect()
+    ///         .await
+    ///         .unwrap();
+    ///     dbg!(radio);
+    /// }
+    /// ```
+    pub fn radio_for_artist<'a>(&'a self, artist: &Artist) -> impl Stream<Item = Result<QueueItem, Box<dyn Error>>> + 'a {
+        let body_vars = artist.radio_playlist_id.as_deref()
+            .map(|playlist_id| watch_context(None, Some(playlist_id), Some(RADIO_PARAMS)))
+            .ok_or(ResponseParseError::MissingValue(format!("radio playlist id of artist {}", artist.name)));
+        self.queue_stream(body_vars)
+    }
+
+    /// Play a radio based on a playlist
+    pub fn radio_for_playlist<'a>(&'a self, playlist_id: &str) -> impl Stream<Item = Result<QueueItem, Box<dyn Error>>> + 'a {
+        let playlist_id = playlist_id.strip_prefix("VL").unwrap_or(playlist_id);
+        let playlist_id = format!("RDAMPL{playlist_id}");
+        self.queue_stream(Ok(watch_context(None, Some(&playlist_id), Some(RADIO_PARAMS))))
+    }
+
+    /// Play an album's tracks in a random order
+    ///
+    /// ```no_run
+    /// use futures::TryStreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let album = client.get_album("MPREb_LJSVi8szMQL").await.unwrap();
+    ///     let shuffled: Vec<_> = client.shuffle(&album).try_collect().await.unwrap();
+    ///     dbg!(shuffled);
+    /// }
+    /// ```
+    pub fn shuffle<'a>(&'a self, album: &Album) -> impl Stream<Item = Result<QueueItem, Box<dyn Error>>> + 'a {
+        let body_vars = album.playlist_id.as_deref()
+            .map(|playlist_id| watch_context(None, Some(playlist_id), Some(SHUFFLE_PARAMS)))
+            .ok_or(ResponseParseError::MissingValue(format!("playlist id of album {}", album.name)));
+        self.queue_stream(body_vars)
+    }
+
+    /// Turn a `next` request into a stream of the queue it starts, following its continuations
+    fn queue_stream(&self, body_vars: Result<Map<String, Value>, ResponseParseError>) -> impl Stream<Item = Result<QueueItem, Box<dyn Error>>> + '_ {
+        let mut first = Some(body_vars);
+        paginate(move |token| {
+            let first = first.take();
+            async move {
+                match token {
+                    None => {
+                        let body_vars = first.expect("the first part of a queue is only requested once")?;
+                        let res = create_api_request(&self.config, "next", body_vars).await?;
+                        let playlist = WatchPlaylist::parse(res)?;
+                        Ok((playlist.tracks, playlist.next))
+                    },
+                    Some(token) => self.watch_next(&token).await,
+                }
+            }
+        })
     }
 
     /// Search an artist by their channel's name
@@ -93,17 +658,133 @@ impl Client {
     ///     }
     /// }
     /// ```
-    pub async fn search_artists(self: &Self, query: &str) -> Result<Vec<ArtistSearchResult>, Box<dyn Error>> {
-        let body_vars = json!({
-            "params": "EgWKAQIgAWoKEAkQChADEAUQBA%3D%3D",
-            "query": query,
-           }).as_object().unwrap().to_owned();
-        let res = create_api_request(&self.config, "search", body_vars)
-            .await?;
+    pub async fn search_artists(&self, query: &str) -> Result<Vec<ArtistSearchResult>, Box<dyn Error>> {
+        Ok(self.search_artists_page(query).await?.items)
+    }
+
+    /// Search an artist by their channel's name, keeping the token for the next page
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let page = client.search_artists_page("Rammstein").await.unwrap();
+    ///     if let Some(next) = page.next {
+    ///         dbg!(client.search_next(&next).await.unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub async fn search_artists_page(&self, query: &str) -> Result<SearchPage<ArtistSearchResult>, Box<dyn Error>> {
+        let res = self.search_with(query, &SearchFilter::new(SearchCategory::Artists)).await?;
         // std::fs::write("res.search.json", res.to_string()).unwrap();
         Ok(ArtistSearchResult::parse(res)?)
     }
+
+    /// Search an artist by exactly the given name, without Youtube correcting its spelling
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let page = client.search_artists_page("Ramstein").await.unwrap();
+    ///     if let Some(original_query) = page.original_query {
+    ///         dbg!(client.search_artists_literal(&original_query).await.unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub async fn search_artists_literal(&self, query: &str) -> Result<SearchPage<ArtistSearchResult>, Box<dyn Error>> {
+        let filter = SearchFilter::new(SearchCategory::Artists).ignore_spelling();
+        let res = self.search_with(query, &filter).await?;
+        Ok(ArtistSearchResult::parse(res)?)
+    }
+
+    /// Request the next page of artist search results
+    pub async fn search_next(&self, token: &ContinuationToken) -> Result<SearchPage<ArtistSearchResult>, Box<dyn Error>> {
+        let res = create_continuation_request(&self.config, "search", &token.0).await?;
+        Ok(ArtistSearchResult::parse_continuation(res)?)
+    }
+
+    /// Search an artist by their channel's name, lazily requesting further pages as the stream is consumed
+    ///
+    /// ```no_run
+    /// use futures::{StreamExt, TryStreamExt};
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results: Vec<_> = client.search_artists_stream("Tři sestry")
+    ///         .take(100)
+    ///         .try_collect()
+    ///         .await
+    ///         .unwrap();
+    ///     dbg!(results.len());
+    /// }
+    /// ```
+    pub fn search_artists_stream<'a>(&'a self, query: &'a str) -> impl Stream<Item = Result<ArtistSearchResult, Box<dyn Error>>> + 'a {
+        paginate(move |token| async move {
+            let page = match token {
+                None => self.search_artists_page(query).await?,
+                Some(token) => self.search_next(&token).await?,
+            };
+            Ok((page.items, page.next))
+        })
+    }
+
+    /// Search an artist by their channel's name, requesting further pages until `limit` results are collected
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_artists_up_to("Tři sestry", 100).await.unwrap();
+    ///     dbg!(results.len());
+    /// }
+    /// ```
+    pub async fn search_artists_up_to(&self, query: &str, limit: usize) -> Result<Vec<ArtistSearchResult>, Box<dyn Error>> {
+        let mut page = self.search_artists_page(query).await?;
+        let mut results = Vec::new();
+        loop {
+            results.append(&mut page.items);
+            match page.next {
+                Some(next) if results.len() < limit => page = self.search_next(&next).await?,
+                _ => break,
+            }
+        }
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    /// Search with a custom filter, leaving the response to the parser of the searched category
+    pub(crate) async fn search_with(&self, query: &str, filter: &SearchFilter) -> Result<Value, reqwest::Error> {
+        let mut body_vars = json!({
+            "query": query,
+           }).as_object().unwrap().to_owned();
+        if let Some(params) = filter.params() {
+            body_vars.insert("params".to_string(), Value::String(params));
+        }
+        create_api_request(&self.config, "search", body_vars).await
+    }
     
+    /// Get type-ahead suggestions for a partially typed query
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let suggestions = client.search_suggestions("rammst").await.unwrap();
+    ///     for suggestion in suggestions.text {
+    ///         println!("{}", suggestion.query);
+    ///     }
+    /// }
+    /// ```
+    pub async fn search_suggestions(&self, partial: &str) -> Result<SearchSuggestions, Box<dyn Error>> {
+        let body_vars = json!({
+            "input": partial,
+        }).as_object().unwrap().to_owned();
+        let res = create_api_request(&self.config, "music/get_search_suggestions", body_vars).await?;
+        Ok(SearchSuggestions::parse(res)?)
+    }
+
     /// Request configs from Youtube music
     /// 
     /// ```no_run
@@ -115,25 +796,10 @@ impl Client {
     /// }
     /// ```
     pub async fn init() -> Result<Client, Box<dyn Error>> {
-        let client = reqwest::Client::new();
-
-        let response = client
-            .get(BASE_URL)
-            .header("User-Agent", "Mozilla/5.0 (X11; Linux x86_64; rv:104.0) Gecko/20100101 Firefox/104.0")
-            .header("Accept", "text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,*//*;q=0.8")
-            .header("Accept-Language", "en-US,en;q=0.5")
-            .header("Upgrade-Insecure-Requests", "1")
-            .header("Sec-Fetch-Dest", "document")
-            .header("Sec-Fetch-Mode", "navigate")
-            .header("Sec-Fetch-Site", "none")
-            .header("Sec-Fetch-User", "?1")
-            .send()
-            .await?
-            .text()
-            .await?;
+        let response = create_page_request(Url::parse(BASE_URL).expect("invalid base url")).await?;
 
         let mut full_config = HashMap::new();
-        for config_unenclosed in response.as_str().split("ytcfg.set(").into_iter().skip(1) {
+        for config_unenclosed in response.as_str().split("ytcfg.set(").skip(1) {
             let config_text = config_unenclosed.split_once(");").ok_or(
                 ResponseParseError::UnclosedConfig(config_unenclosed.to_string())
             )?.0;
@@ -1,46 +1,124 @@
+mod charts;
+#[cfg(test)]
+mod fixtures;
+mod home;
+mod lyrics;
+mod moods;
+mod playlist;
+mod podcast;
 mod pointers;
+mod related;
+mod song;
+mod suggestions;
+mod watch;
 
-use std::{fmt::Display, error::Error};
+use std::{fmt::Display, error::Error, time::Duration};
 
 use serde_json::Value;
 
 use crate::Client;
 
 use self::pointers::*;
+pub use self::charts::*;
+pub use self::home::*;
+pub use self::lyrics::*;
+pub use self::moods::*;
+pub use self::playlist::*;
+pub use self::podcast::*;
+pub use self::related::*;
+pub use self::song::*;
+pub use self::suggestions::*;
+pub use self::watch::*;
 
 #[derive(Debug, Clone)]
 pub struct Artist {
     pub name: String,
     pub description: Option<String>,
+    pub subscribers: Option<u64>,
+    pub views: Option<u64>,
+    /// Id of the channel behind the artist's page, which can differ from the page's browse id
+    pub channel_id: Option<String>,
+    /// Playlist id for shuffling the artist's songs
+    pub shuffle_playlist_id: Option<String>,
+    /// Playlist id of the artist's radio
+    pub radio_playlist_id: Option<String>,
+    /// Banner images
+    pub thumbnails: Vec<Thumbnail>,
+    pub top_songs: Vec<ArtistSong>,
+    /// Browse id of the playlist with all of the artist's songs
+    pub top_songs_browse_id: Option<String>,
+    /// Albums shown on the artist's page, see [`Artist::full_albums`] for all of them
     pub albums: Vec<Product>,
+    /// Singles and EPs shown on the artist's page, see [`Artist::full_singles`] for all of them
     pub singles: Vec<Product>,
-    //views: &'a str,
-    //thumbnails: &'a str,
+    /// Where to find the complete list of albums, if the page doesn't show all of them
+    pub albums_endpoint: Option<BrowseEndpoint>,
+    /// Where to find the complete list of singles and EPs, if the page doesn't show all of them
+    pub singles_endpoint: Option<BrowseEndpoint>,
+    pub videos: Vec<ArtistVideo>,
+    /// Browse id of the playlist with all of the artist's videos
+    pub videos_browse_id: Option<String>,
+    /// Artists from the "Fans might also like" section
+    pub related: Vec<ArtistSearchResult>,
+    /// Playlists from other channels featuring the artist
+    pub featured_on: Vec<PlaylistRef>,
+    /// Playlists made by the artist
+    pub playlists: Vec<PlaylistRef>,
 }
 
 impl Artist {
-    pub(crate) fn parse(res: Value) -> Result<Self, ResponseParseError> {
+    pub(crate) fn parse(res: Value, browse_id: &str) -> Result<Self, ResponseParseError> {
+        let sections: Vec<&Value> = iter_from_json(&res, ARTIST_SECTIONS)?.collect();
+        let channel_id = string_from_json(&res, ARTIST_CHANNEL_ID).ok();
+        let songs_shelf = sections.iter().find_map(|section| section.pointer(ARTIST_SONGS_SHELF));
+        // singles and EPs share a carousel, which comes after the albums one
+        let (singles_carousels, albums_carousels): (Vec<&Value>, Vec<&Value>) = sections.iter().copied()
+            .filter(|section| section.pointer(CAROUSEL_ITEMS).and_then(|items| items.get(0))
+                .and_then(|item| item.pointer(TWO_ROW_PAGE_TYPE)?.as_str()) == Some("MUSIC_PAGE_TYPE_ALBUM"))
+            .partition(|carousel| iter_from_json(carousel, CAROUSEL_ITEMS).into_iter().flatten().any(is_single));
+        let albums_carousel = albums_carousels.first().copied();
+        let singles_carousel = singles_carousels.first().or(albums_carousels.get(1)).copied();
+        let videos_carousel = find_carousel(&sections, |item| item.pointer(TWO_ROW_WATCH).is_some());
+        let related_carousel = find_carousel(&sections, |item| {
+            item.pointer(TWO_ROW_PAGE_TYPE).and_then(Value::as_str) == Some("MUSIC_PAGE_TYPE_ARTIST")
+        });
+        // the artist's own playlists link their author to the artist
+        let (playlists, featured_on): (Vec<&Value>, Vec<&Value>) = sections.iter()
+            .filter(|section| section.pointer(CAROUSEL_ITEMS).and_then(|items| items.get(0))
+                .and_then(|item| item.pointer(TWO_ROW_PAGE_TYPE)?.as_str()) == Some("MUSIC_PAGE_TYPE_PLAYLIST"))
+            .filter_map(|carousel| iter_from_json(carousel, CAROUSEL_ITEMS).ok())
+            .flatten()
+            .partition(|item| iter_from_json(item, TWO_ROW_SUBTITLE).into_iter().flatten()
+                .filter_map(|run| run.pointer(RUN_BROWSE_ID)?.as_str())
+                .any(|author_id| author_id == browse_id || Some(author_id) == channel_id.as_deref()));
+
         Ok(Artist {
             name: string_from_json(&res, ARTIST_NAME)?,
-            description: res.pointer(ARTIST_DESCIPTION).and_then(
-                |it| Some(it.as_str()?.to_string())
-            ),
-            albums: iter_from_json(&res, ARTIST_ALBUMS)?.filter_map(|item| -> Option<Product> {
-                Some(Product {
-                    name: string_from_json(&item, ARTIST_PRODUCT_NAME).ok()?,
-                    browse_id: string_from_json(&item, ARTIST_PRODUCT_ID).ok()?,
-                    year: string_from_json(&item, ARTIST_ALBUM_YEAR).ok()?,
-                    thumbnails: thumbnails_from_json(&item, ARTIST_PRODUCT_THUMBS).ok()?,
-                })
-            }).collect(),
-            singles: iter_from_json(&res, ARTIST_SINGLES)?.filter_map(|item| -> Option<Product> {
-                Some(Product {
-                    name: string_from_json(&item, ARTIST_PRODUCT_NAME).ok()?,
-                    browse_id: string_from_json(&item, ARTIST_PRODUCT_ID).ok()?,
-                    year: string_from_json(&item, ARTIST_SINGLE_YEAR).ok()?,
-                    thumbnails: thumbnails_from_json(&item, ARTIST_PRODUCT_THUMBS).ok()?,
-                })
-            }).collect(),
+            description: runs_text_from_json(&res, ARTIST_DESCIPTION).ok(),
+            subscribers: string_from_json(&res, ARTIST_SUBSCRIBERS).ok().and_then(|it| count_from_text(&it)),
+            views: sections.iter().find_map(|section| string_from_json(section, ARTIST_ABOUT_VIEWS).ok())
+                .and_then(|it| count_from_text(&it)),
+            channel_id,
+            shuffle_playlist_id: string_from_json(&res, ARTIST_SHUFFLE_PLAYLIST_ID).ok(),
+            radio_playlist_id: string_from_json(&res, ARTIST_RADIO_PLAYLIST_ID).ok(),
+            thumbnails: thumbnails_from_json(&res, ARTIST_THUMBS).unwrap_or_default(),
+            top_songs: songs_shelf.and_then(|shelf| iter_from_json(shelf, SHELF_ITEMS).ok())
+                .map(|items| items.filter_map(ArtistSong::parse).collect())
+                .unwrap_or_default(),
+            top_songs_browse_id: songs_shelf.and_then(|shelf| string_from_json(shelf, ARTIST_SONGS_BROWSE_ID).ok()),
+            albums: Product::parse_carousel(albums_carousel),
+            singles: Product::parse_carousel(singles_carousel),
+            albums_endpoint: albums_carousel.and_then(discography_endpoint),
+            singles_endpoint: singles_carousel.and_then(discography_endpoint),
+            videos: videos_carousel.and_then(|carousel| iter_from_json(carousel, CAROUSEL_ITEMS).ok())
+                .map(|items| items.filter_map(ArtistVideo::parse).collect())
+                .unwrap_or_default(),
+            videos_browse_id: videos_carousel.and_then(|carousel| string_from_json(carousel, CAROUSEL_MORE_BROWSE_ID).ok()),
+            related: related_carousel.and_then(|carousel| iter_from_json(carousel, CAROUSEL_ITEMS).ok())
+                .map(|items| items.filter_map(ArtistSearchResult::parse_related).collect())
+                .unwrap_or_default(),
+            featured_on: featured_on.into_iter().filter_map(PlaylistRef::parse).collect(),
+            playlists: playlists.into_iter().filter_map(PlaylistRef::parse).collect(),
         })
     }
 
@@ -48,6 +126,355 @@ impl Artist {
     pub fn products(&self) -> Vec<&Product> {
         self.albums.iter().chain(&self.singles).collect()
     }
+
+    /// Request all of the artist's albums, not just the ones shown on their page
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_artists("Rammstein").await.unwrap();
+    ///     if let Some(first_result) = results.into_iter().next() {
+    ///         let artist = first_result.request(&client).await.unwrap();
+    ///         dbg!(artist.full_albums(&client).await.unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub async fn full_albums(&self, client: &Client) -> Result<Vec<Product>, Box<dyn Error>> {
+        match &self.albums_endpoint {
+            Some(endpoint) => client.browse_discography(endpoint).await,
+            None => Ok(self.albums.clone()),
+        }
+    }
+
+    /// Request all of the artist's singles and EPs, not just the ones shown on their page
+    pub async fn full_singles(&self, client: &Client) -> Result<Vec<Product>, Box<dyn Error>> {
+        match &self.singles_endpoint {
+            Some(endpoint) => client.browse_discography(endpoint).await,
+            None => Ok(self.singles.clone()),
+        }
+    }
+
+    /// Request all of the artist's songs, not just the top ones
+    ///
+    /// ```no_run
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = youtube_music::Client::init().await.unwrap();
+    ///     let results = client.search_artists("Rammstein").await.unwrap();
+    ///     if let Some(first_result) = results.into_iter().next() {
+    ///         let artist = first_result.request(&client).await.unwrap();
+    ///         dbg!(artist.all_songs(&client).await.unwrap());
+    ///     }
+    /// }
+    /// ```
+    pub async fn all_songs(&self, client: &Client) -> Result<Vec<ArtistSong>, Box<dyn Error>> {
+        match &self.top_songs_browse_id {
+            Some(browse_id) => client.get_artist_songs(browse_id).await,
+            None => Ok(self.top_songs.clone()),
+        }
+    }
+}
+
+/// A song from an artist's page, their songs playlist or another song's related content
+#[derive(Debug, Clone)]
+pub struct ArtistSong {
+    pub name: String,
+    pub video_id: String,
+    pub artists: Vec<ArtistRef>,
+    pub album: Option<AlbumRef>,
+    /// Only present on the artist's page
+    pub plays: Option<u64>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl ArtistSong {
+    pub(crate) fn parse(item: &Value) -> Option<Self> {
+        Some(ArtistSong {
+            name: string_from_json(item, SONG_NAME).ok()?,
+            video_id: string_from_json(item, SONG_ID).ok()?,
+            // the column can continue with the album after a separator
+            artists: ArtistRef::parse_runs(iter_from_json(item, ALBUM_TRACK_ARTISTS).into_iter().flatten()
+                .take_while(|run| run.pointer(RUN_TEXT).and_then(Value::as_str) != Some(" • "))),
+            album: column_runs(item)
+                .find(|run| run.pointer(RUN_PAGE_TYPE).and_then(Value::as_str) == Some("MUSIC_PAGE_TYPE_ALBUM"))
+                .and_then(AlbumRef::parse),
+            plays: item.pointer(SONG_PLAYS)
+                .filter(|run| run.pointer(RUN_ENDPOINT).is_none())
+                .and_then(|run| count_from_text(run.pointer(RUN_TEXT)?.as_str()?)),
+            thumbnails: thumbnails_from_json(item, SONG_THUMBS).unwrap_or_default(),
+        })
+    }
+
+    pub(crate) fn parse_playlist(res: Value) -> Result<Continued<Self>, ResponseParseError> {
+        let (items, next) = shelf_page(&res, PLAYLIST_SHELF, CONTINUED_PLAYLIST_SHELF, SHELF_ITEMS)?;
+        Ok((items.into_iter().filter_map(Self::parse).collect(), next))
+    }
+}
+
+/// A music video from an artist's page or their videos playlist
+#[derive(Debug, Clone)]
+pub struct ArtistVideo {
+    pub name: String,
+    pub video_id: String,
+    pub views: Option<u64>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub video_type: Option<MusicVideoType>,
+}
+
+impl ArtistVideo {
+    /// Parse from either a carousel item or a playlist item
+    pub(crate) fn parse(item: &Value) -> Option<Self> {
+        if let Some(watch) = item.pointer(TWO_ROW_WATCH) {
+            let subtitle: Vec<&Value> = iter_from_json(item, TWO_ROW_SUBTITLE).ok()?.collect();
+            return Some(ArtistVideo {
+                name: string_from_json(item, TWO_ROW_TITLE).ok()?,
+                video_id: string_from_json(watch, WATCH_VIDEO_ID).ok()?,
+                views: subtitle.last().filter(|_| subtitle.len() > 1)
+                    .and_then(|run| count_from_text(run.pointer(RUN_TEXT)?.as_str()?)),
+                thumbnails: thumbnails_from_json(item, TWO_ROW_THUMBS).unwrap_or_default(),
+                video_type: MusicVideoType::parse(watch),
+            });
+        }
+
+        Some(ArtistVideo {
+            name: string_from_json(item, SONG_NAME).ok()?,
+            video_id: string_from_json(item, SONG_ID).ok()?,
+            views: column_runs(item)
+                .filter(|run| run.pointer(RUN_ENDPOINT).is_none())
+                .filter_map(|run| run.pointer(RUN_TEXT)?.as_str())
+                .find(|text| text.ends_with("views"))
+                .and_then(count_from_text),
+            thumbnails: thumbnails_from_json(item, SONG_THUMBS).unwrap_or_default(),
+            video_type: item.pointer(SONG_WATCH).and_then(MusicVideoType::parse),
+        })
+    }
+
+    pub(crate) fn parse_playlist(res: Value) -> Result<Continued<Self>, ResponseParseError> {
+        let (items, next) = shelf_page(&res, PLAYLIST_SHELF, CONTINUED_PLAYLIST_SHELF, SHELF_ITEMS)?;
+        Ok((items.into_iter().filter_map(Self::parse).collect(), next))
+    }
+}
+
+/// What kind of a video a watch endpoint points to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MusicVideoType {
+    /// A song with a static album cover
+    Atv,
+    /// An official music video
+    Omv,
+    /// A video uploaded by a user
+    Ugc,
+    OfficialSourceMusic,
+    PodcastEpisode,
+    Other(String),
+}
+
+impl MusicVideoType {
+    /// Parse from a watch endpoint
+    pub(crate) fn parse(watch: &Value) -> Option<Self> {
+        Some(Self::from_name(watch.pointer(WATCH_VIDEO_TYPE)?.as_str()?))
+    }
+
+    /// Parse from a name such as `MUSIC_VIDEO_TYPE_ATV`
+    pub(crate) fn from_name(name: &str) -> Self {
+        match name {
+            "MUSIC_VIDEO_TYPE_ATV" => MusicVideoType::Atv,
+            "MUSIC_VIDEO_TYPE_OMV" => MusicVideoType::Omv,
+            "MUSIC_VIDEO_TYPE_UGC" => MusicVideoType::Ugc,
+            "MUSIC_VIDEO_TYPE_OFFICIAL_SOURCE_MUSIC" => MusicVideoType::OfficialSourceMusic,
+            "MUSIC_VIDEO_TYPE_PODCAST_EPISODE" => MusicVideoType::PodcastEpisode,
+            other => MusicVideoType::Other(other.to_string()),
+        }
+    }
+}
+
+/// Which part of an artist's discography to request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiscographyKind {
+    Albums,
+    /// Singles and EPs
+    Singles,
+}
+
+/// A link to a page which needs more than its browse id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BrowseEndpoint {
+    pub browse_id: String,
+    pub params: Option<String>,
+}
+
+impl BrowseEndpoint {
+    pub(crate) fn parse(endpoint: &Value) -> Option<Self> {
+        Some(BrowseEndpoint {
+            browse_id: string_from_json(endpoint, BROWSE_ID).ok()?,
+            params: string_from_json(endpoint, BROWSE_PARAMS).ok(),
+        })
+    }
+}
+
+/// A link to a playlist
+#[derive(Debug, Clone)]
+pub struct PlaylistRef {
+    pub title: String,
+    pub browse_id: String,
+    /// The browse id without its `VL` prefix
+    pub playlist_id: String,
+    pub author: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl PlaylistRef {
+    /// Parse from a carousel item
+    pub(crate) fn parse(item: &Value) -> Option<Self> {
+        let browse_id = string_from_json(item, TWO_ROW_BROWSE_ID).ok()?;
+        // the subtitle is either only the author or "Playlist • author"
+        let subtitle: Vec<String> = iter_from_json(item, TWO_ROW_SUBTITLE).ok()?
+            .filter_map(|run| run.pointer(RUN_TEXT)?.as_str())
+            .filter(|text| *text != " • ")
+            .map(str::to_string)
+            .collect();
+
+        Some(PlaylistRef {
+            title: string_from_json(item, TWO_ROW_TITLE).ok()?,
+            playlist_id: browse_id.strip_prefix("VL").unwrap_or(&browse_id).to_string(),
+            browse_id,
+            author: subtitle.get(if subtitle.len() > 1 { 1 } else { 0 }).cloned(),
+            thumbnails: thumbnails_from_json(item, TWO_ROW_THUMBS).unwrap_or_default(),
+        })
+    }
+
+    /// Request the playlist with all of its tracks
+    pub async fn request(&self, client: &Client) -> Result<Playlist, Box<dyn Error>> {
+        client.get_playlist(&self.playlist_id).await
+    }
+}
+
+/// A link to an artist, which may be missing for artists without a page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArtistRef {
+    pub name: String,
+    pub browse_id: Option<String>,
+}
+
+impl ArtistRef {
+    /// Parse from text runs such as "Artist, Artist & Artist", skipping the separators
+    pub(crate) fn parse_runs<'a>(runs: impl Iterator<Item = &'a Value>) -> Vec<Self> {
+        runs.filter_map(|run| {
+            let name = string_from_json(run, RUN_TEXT).ok()?;
+            let browse_id = string_from_json(run, RUN_BROWSE_ID).ok();
+            if browse_id.is_none() && [", ", " & ", " • ", " and "].contains(&name.as_str()) {
+                return None;
+            }
+            Some(ArtistRef { name, browse_id })
+        }).collect()
+    }
+}
+
+/// A description with its source attribution, usually from Wikipedia
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Description {
+    pub text: String,
+    pub attribution: Option<Attribution>,
+}
+
+/// Where a description comes from and under which license, as it should be displayed
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attribution {
+    /// Such as "From Wikipedia (url) under Creative Commons Attribution CC-BY-SA 3.0 (url)"
+    pub text: String,
+    pub source_url: Option<String>,
+    pub license_url: Option<String>,
+}
+
+impl Description {
+    /// Parse from text runs, where the attribution starts in the last paragraph before the first link
+    pub(crate) fn parse<'a>(runs: impl Iterator<Item = &'a Value>) -> Self {
+        // links are shortened in the text, so the full urls are used instead
+        let runs: Vec<(String, Option<String>)> = runs.filter_map(|run| {
+            let url = string_from_json(run, RUN_URL).ok();
+            Some((url.clone().or_else(|| string_from_json(run, RUN_TEXT).ok())?, url))
+        }).collect();
+        let text_of = |runs: &[(String, Option<String>)]| runs.iter().map(|(text, _)| text.as_str()).collect::<String>();
+
+        let Some(first_link) = runs.iter().position(|(_, url)| url.is_some()) else {
+            return Description { text: text_of(&runs), attribution: None };
+        };
+        let before_link = text_of(&runs[..first_link]);
+        let (text, attribution_start) = before_link.rsplit_once("\n\n").unwrap_or(("", &before_link));
+        let mut urls = runs.iter().filter_map(|(_, url)| url.clone());
+
+        Description {
+            text: text.to_string(),
+            attribution: Some(Attribution {
+                text: format!("{attribution_start}{}", text_of(&runs[first_link..])),
+                source_url: urls.next(),
+                license_url: urls.next(),
+            }),
+        }
+    }
+}
+
+/// Find an album's browse id in the html of its playlist page
+pub(crate) fn album_browse_id_from_html(html: &str) -> Option<String> {
+    let start = html.find("MPREb_")?;
+    let id: String = html[start..].chars()
+        .take_while(|char| char.is_ascii_alphanumeric() || *char == '_' || *char == '-')
+        .collect();
+    Some(id)
+}
+
+/// Read the number from a text such as "1,234 songs" or "7.1M plays"
+///
+/// Numbers with a suffix are approximate, as Youtube rounds them to a few significant digits.
+fn count_from_text(text: &str) -> Option<u64> {
+    let number = text.split_whitespace().next()?.replace(',', "");
+    let (number, multiplier) = match number.char_indices().last()? {
+        (i, 'K') => (&number[..i], 1e3),
+        (i, 'M') => (&number[..i], 1e6),
+        (i, 'B') => (&number[..i], 1e9),
+        _ => return number.parse().ok(),
+    };
+    Some((number.parse::<f64>().ok()? * multiplier).round() as u64)
+}
+
+/// Read a duration from a text such as "3:09" or "1:02:03"
+fn duration_from_text(text: &str) -> Option<Duration> {
+    let mut seconds = 0;
+    for part in text.split(':') {
+        seconds = seconds * 60 + part.trim().parse::<u64>().ok()?;
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// A link to an album
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlbumRef {
+    pub name: String,
+    pub browse_id: String,
+}
+
+impl AlbumRef {
+    /// Parse from a text run linking to the album
+    pub(crate) fn parse(run: &Value) -> Option<Self> {
+        Some(AlbumRef {
+            name: string_from_json(run, RUN_TEXT).ok()?,
+            browse_id: string_from_json(run, RUN_BROWSE_ID).ok()?,
+        })
+    }
+
+    /// Parse from a carousel item
+    pub(crate) fn parse_two_row(item: &Value) -> Option<Self> {
+        Some(AlbumRef {
+            name: string_from_json(item, TWO_ROW_TITLE).ok()?,
+            browse_id: string_from_json(item, TWO_ROW_BROWSE_ID).ok()?,
+        })
+    }
+
+    /// Request the album
+    pub async fn request(&self, client: &Client) -> Result<Album, Box<dyn Error>> {
+        client.get_album(&self.browse_id).await
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -56,9 +483,41 @@ pub struct Product {
     pub browse_id: String,
     pub year: String,
     pub thumbnails: Vec<Thumbnail>,
+    /// Id of the playlist for playing the whole album
+    pub playlist_id: Option<String>,
 }
 
 impl Product {
+    /// Parse the items of an albums or singles carousel on an artist's page
+    fn parse_carousel(carousel: Option<&Value>) -> Vec<Self> {
+        carousel.and_then(|carousel| iter_from_json(carousel, CAROUSEL_ITEMS).ok()).into_iter().flatten()
+            .filter_map(|item| {
+                Some(Product {
+                    name: string_from_json(item, ARTIST_PRODUCT_NAME).ok()?,
+                    browse_id: string_from_json(item, ARTIST_PRODUCT_ID).ok()?,
+                    year: product_year(item)?,
+                    thumbnails: thumbnails_from_json(item, ARTIST_PRODUCT_THUMBS).ok()?,
+                    playlist_id: string_from_json(item, TWO_ROW_PLAYLIST_ID).ok(),
+                })
+            }).collect()
+    }
+
+    /// Parse from an item of the discography page
+    pub(crate) fn parse_discography(item: &Value) -> Option<Self> {
+        Some(Product {
+            name: string_from_json(item, TWO_ROW_TITLE).ok()?,
+            browse_id: string_from_json(item, TWO_ROW_BROWSE_ID).ok()?,
+            year: product_year(item)?,
+            thumbnails: thumbnails_from_json(item, TWO_ROW_THUMBS).unwrap_or_default(),
+            playlist_id: string_from_json(item, TWO_ROW_PLAYLIST_ID).ok(),
+        })
+    }
+
+    pub(crate) fn parse_discography_page(res: Value) -> Result<Continued<Self>, ResponseParseError> {
+        let (items, next) = shelf_page(&res, DISCOGRAPHY_GRID, CONTINUED_GRID, GRID_ITEMS)?;
+        Ok((items.into_iter().filter_map(Self::parse_discography).collect(), next))
+    }
+
     /// Request a product
     ///
     /// ```no_run
@@ -74,7 +533,7 @@ impl Product {
     ///     }
     /// }
     /// ```
-    pub async fn request(self: &Self, client: &Client) -> Result<Album, Box<dyn Error>> {
+    pub async fn request(&self, client: &Client) -> Result<Album, Box<dyn Error>> {
         client.get_album(&self.browse_id).await
     }
 }
@@ -82,32 +541,62 @@ impl Product {
 #[derive(Debug, Clone)]
 pub struct Album {
     pub name: String,
-    // pub browse_id: String,
+    pub browse_id: String,
+    /// Such as "Album", "Single" or "EP"
+    pub album_type: Option<String>,
+    /// Id of the playlist for playing the whole album, starting with `OLAK5uy_`
+    pub playlist_id: Option<String>,
+    pub artists: Vec<ArtistRef>,
     pub year: String,
+    pub track_count: Option<usize>,
+    /// In an "n minutes" format
+    pub duration: Option<String>,
+    pub description: Option<Description>,
+    /// Tracks of all discs
     pub tracks: Vec<Track>,
+    /// Other editions of the album, such as deluxe or remastered ones
+    pub other_versions: Vec<AlbumRef>,
     pub thumbnails: Vec<Thumbnail>,
 }
 
 impl Album {
-    pub(crate) fn parse(res: Value) -> Result<Self, ResponseParseError> {
+    pub(crate) fn parse(res: Value, browse_id: &str) -> Result<Self, ResponseParseError> {
+        let sections: Vec<&Value> = iter_from_json(&res, ALBUM_SECTIONS)?.collect();
+        // multi-disc releases can come in a shelf per disc
+        let shelves: Vec<Vec<&Value>> = sections.iter()
+            .filter_map(|section| Some(iter_from_json(section, ALBUM_SECTION_TRACKS).ok()?.collect()))
+            .collect();
+        if shelves.is_empty() {
+            return Err(ResponseParseError::MissingValue(format!("{ALBUM_SECTIONS}/*{ALBUM_SECTION_TRACKS}")));
+        }
+        let other_versions_carousel = find_carousel(&sections, |item| {
+            item.pointer(TWO_ROW_PAGE_TYPE).and_then(Value::as_str) == Some("MUSIC_PAGE_TYPE_ALBUM")
+        });
+
         Ok(Album {
             name: string_from_json(&res, ALBUM_NAME)?,
+            browse_id: browse_id.to_string(),
+            album_type: string_from_json(&res, ALBUM_TYPE).ok(),
+            playlist_id: string_from_json(&res, ALBUM_URL).ok()
+                .and_then(|url| Some(url.split_once("list=")?.1.to_string()))
+                .or_else(|| shelves.iter().flatten()
+                    .find_map(|item| string_from_json(item, ALBUM_TRACK_PLAYLIST_ID).ok())),
+            artists: iter_from_json(&res, ALBUM_ARTISTS).map(ArtistRef::parse_runs).unwrap_or_default(),
             year: string_from_json(&res, ALBUM_YEAR)?,
-            tracks: iter_from_json(&res, ALBUM_TRACKS)?
-            .enumerate().filter_map(|(track_num, item)| -> Option<Track> {
-                Some(Track {
-                    name: string_from_json(&item, ALBUM_TRACK_NAME).ok()?,
-                    video_id: string_from_json(&item, ALBUM_TRACK_ID).ok()?,
-                    track_num: track_num + 1,
-                })
-            }).collect(),
+            track_count: string_from_json(&res, ALBUM_TRACK_COUNT).ok().and_then(|it| Some(count_from_text(&it)? as usize)),
+            duration: string_from_json(&res, ALBUM_DURATION).ok(),
+            description: iter_from_json(&res, ALBUM_DESCRIPTION).ok().map(Description::parse),
+            tracks: Track::parse_shelves(&shelves),
+            other_versions: other_versions_carousel.and_then(|carousel| iter_from_json(carousel, CAROUSEL_ITEMS).ok())
+                .map(|items| items.filter_map(AlbumRef::parse_two_row).collect())
+                .unwrap_or_default(),
             thumbnails: iter_from_json(&res, ALBUM_THUMBS)?
             .filter_map(|thumbnail| -> Option<Thumbnail> {
                 Some(Thumbnail {
-                    url: string_from_json(&thumbnail, THUMBNAIL_URL).ok()?,
+                    url: string_from_json(thumbnail, THUMBNAIL_URL).ok()?,
                     size: (
-                        value_from_json(&thumbnail, THUMBNAIL_WIDTH).ok()?.as_u64()? as usize,
-                        value_from_json(&thumbnail, THUMBNAIL_HEIGHT).ok()?.as_u64()? as usize,
+                        value_from_json(thumbnail, THUMBNAIL_WIDTH).ok()?.as_u64()? as usize,
+                        value_from_json(thumbnail, THUMBNAIL_HEIGHT).ok()?.as_u64()? as usize,
                     ),
                 })
             }).collect(),
@@ -118,8 +607,62 @@ impl Album {
 #[derive(Debug, Clone)]
 pub struct Track {
     pub name: String,
-    pub video_id: String,
+    /// Missing for some unavailable tracks
+    pub video_id: Option<String>,
     pub track_num: usize,
+    /// Starting at 1, also for single disc releases
+    pub disc_number: usize,
+    pub duration: Option<Duration>,
+    pub plays: Option<u64>,
+    /// Artists featured on the track, empty if they are the same as the album's
+    pub artists: Vec<ArtistRef>,
+    pub explicit: bool,
+    /// Whether the track can be played, unavailable tracks are greyed out on the album's page
+    pub available: bool,
+}
+
+impl Track {
+    /// Parse tracks from all of an album's shelves,
+    /// counting a new disc for each shelf and whenever the track numbers start over
+    pub(crate) fn parse_shelves(shelves: &[Vec<&Value>]) -> Vec<Self> {
+        let mut tracks: Vec<Track> = Vec::new();
+        let mut disc_number = 0;
+        for shelf in shelves {
+            disc_number += 1;
+            let first_of_shelf = tracks.len();
+            for (position, item) in shelf.iter().enumerate() {
+                let Some(mut track) = Track::parse(item, position, disc_number) else {
+                    continue;
+                };
+                if tracks.len() > first_of_shelf && tracks.last().is_some_and(|last| track.track_num <= last.track_num) {
+                    disc_number += 1;
+                    track.disc_number = disc_number;
+                }
+                tracks.push(track);
+            }
+        }
+        tracks
+    }
+
+    /// Parse from an album shelf item, `position` being its index in the shelf
+    pub(crate) fn parse(item: &Value, position: usize, disc_number: usize) -> Option<Self> {
+        Some(Track {
+            name: string_from_json(item, ALBUM_TRACK_NAME).ok()?,
+            video_id: string_from_json(item, ALBUM_TRACK_ID)
+                .or_else(|_| string_from_json(item, SONG_ID)).ok(),
+            track_num: string_from_json(item, ALBUM_TRACK_INDEX).ok()
+                .and_then(|it| it.parse().ok())
+                .unwrap_or(position + 1),
+            disc_number,
+            duration: string_from_json(item, ALBUM_TRACK_DURATION).ok().and_then(|it| duration_from_text(&it)),
+            plays: string_from_json(item, ALBUM_TRACK_PLAYS).ok().and_then(|it| count_from_text(&it)),
+            artists: iter_from_json(item, ALBUM_TRACK_ARTISTS).map(ArtistRef::parse_runs).unwrap_or_default(),
+            explicit: iter_from_json(item, ALBUM_TRACK_BADGES).into_iter().flatten()
+                .any(|badge| badge.pointer(BADGE_ICON).and_then(Value::as_str) == Some("MUSIC_EXPLICIT_BADGE")),
+            available: item.pointer(ALBUM_TRACK_DISPLAY_POLICY).and_then(Value::as_str)
+                != Some("MUSIC_ITEM_RENDERER_DISPLAY_POLICY_GREY_OUT"),
+        })
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -136,7 +679,7 @@ impl Ord for Thumbnail {
 
 impl PartialOrd for Thumbnail {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.size.partial_cmp(&other.size)
+        Some(self.cmp(other))
     }
 }
 
@@ -156,14 +699,29 @@ pub struct ArtistSearchResult {
 }
 
 impl ArtistSearchResult {
-    pub(crate) fn parse(res: Value) -> Result<Vec<Self>, ResponseParseError> {
-        Ok(iter_from_json(&res, SEARCHED_ARTISTS)?.filter_map(|item| -> Option<Self> {
-            Some(Self {
-                name: string_from_json(&item, SEARCHED_ARTIST_NAME).ok()?,
-                subs: string_from_json(&item, SEARCHED_ARTIST_SUBS).ok()?,
-                browse_id: string_from_json(&item, SEARCHED_ARTIST_ID).ok()?,
-            })
-        }).collect())
+    pub(crate) fn parse(res: Value) -> Result<SearchPage<Self>, ResponseParseError> {
+        SearchPage::parse(&res, Self::parse_item)
+    }
+
+    pub(crate) fn parse_continuation(res: Value) -> Result<SearchPage<Self>, ResponseParseError> {
+        SearchPage::parse_continuation(&res, Self::parse_item)
+    }
+
+    fn parse_item(item: &Value) -> Option<Self> {
+        Some(Self {
+            name: string_from_json(item, SEARCHED_ARTIST_NAME).ok()?,
+            subs: string_from_json(item, SEARCHED_ARTIST_SUBS).ok()?,
+            browse_id: string_from_json(item, SEARCHED_ARTIST_ID).ok()?,
+        })
+    }
+
+    /// Parse from a carousel item on an artist's page
+    pub(crate) fn parse_related(item: &Value) -> Option<Self> {
+        Some(Self {
+            name: string_from_json(item, TWO_ROW_TITLE).ok()?,
+            subs: runs_text_from_json(item, TWO_ROW_SUBTITLE).ok()?,
+            browse_id: string_from_json(item, TWO_ROW_BROWSE_ID).ok()?,
+        })
     }
     
     /// Request a product
@@ -178,11 +736,122 @@ impl ArtistSearchResult {
     ///     }
     /// }
     /// ```
-    pub async fn request(self: &Self, client: &Client) -> Result<Artist, Box<dyn Error>> {
+    pub async fn request(&self, client: &Client) -> Result<Artist, Box<dyn Error>> {
         client.get_artist(&self.browse_id).await
     }
 }
 
+/// One page of search results
+#[derive(Debug, Clone)]
+pub struct SearchPage<T> {
+    pub items: Vec<T>,
+    /// Token for requesting the next page, if there is one
+    pub next: Option<ContinuationToken>,
+    /// The query Youtube searched for instead, if it corrected the spelling
+    pub corrected_query: Option<String>,
+    /// The query as it was typed, if Youtube corrected its spelling
+    pub original_query: Option<String>,
+}
+
+impl<T> SearchPage<T> {
+    fn parse(res: &Value, parse_item: impl FnMut(&Value) -> Option<T>) -> Result<Self, ResponseParseError> {
+        // a spelling correction banner or other messages can come before the results
+        let sections: Vec<&Value> = iter_from_json(res, SEARCH_SECTIONS)?.collect();
+        let shelf = sections.iter()
+            .find_map(|section| section.pointer(SEARCH_SECTION_SHELF))
+            .ok_or(ResponseParseError::MissingValue(format!("{SEARCH_SECTIONS}/*{SEARCH_SECTION_SHELF}")))?;
+        let correction = sections.iter()
+            .filter_map(|section| iter_from_json(section, SEARCH_SECTION_MESSAGES).ok())
+            .flatten()
+            .find_map(|message| message.pointer(SEARCH_CORRECTION));
+
+        Ok(SearchPage {
+            items: iter_from_json(shelf, SHELF_ITEMS)?.filter_map(parse_item).collect(),
+            next: continuation_from_json(shelf, SHELF_CONTINUATION),
+            corrected_query: correction.and_then(|it| runs_text_from_json(it, SEARCH_CORRECTED_QUERY).ok()),
+            original_query: correction.and_then(|it| runs_text_from_json(it, SEARCH_ORIGINAL_QUERY).ok()),
+        })
+    }
+
+    fn parse_continuation(res: &Value, parse_item: impl FnMut(&Value) -> Option<T>) -> Result<Self, ResponseParseError> {
+        Ok(SearchPage {
+            items: iter_from_json(res, CONTINUED_SHELF_ITEMS)?.filter_map(parse_item).collect(),
+            next: continuation_from_json(res, CONTINUED_SHELF_CONTINUATION),
+            corrected_query: None,
+            original_query: None,
+        })
+    }
+}
+
+/// An opaque token pointing to the next page of a paginated response
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContinuationToken(pub String);
+
+/// Items of one part of a paginated response, with the token for the next part
+pub(crate) type Continued<T> = (Vec<T>, Option<ContinuationToken>);
+
+/// Get the items of a shelf and the token for its next part from either the first or a continued response
+fn shelf_page<'a>(res: &'a Value, shelf: &str, continued_shelf: &str, items: &str) -> Result<Continued<&'a Value>, ResponseParseError> {
+    if let Some(items) = res.pointer(APPENDED_CONTINUATION_ITEMS) {
+        let items: Vec<&Value> = iter_from_json(items, "")?.collect();
+        let next = items.last().and_then(|item| continuation_from_json(item, CONTINUATION_ITEM_TOKEN));
+        return Ok((items, next));
+    }
+
+    let shelf = match res.pointer(continued_shelf) {
+        Some(it) => it,
+        None => value_from_json(res, shelf)?,
+    };
+    let items: Vec<&Value> = iter_from_json(shelf, items)?.collect();
+    let next = continuation_from_json(shelf, SHELF_CONTINUATION)
+        .or_else(|| items.last().and_then(|item| continuation_from_json(item, CONTINUATION_ITEM_TOKEN)));
+    Ok((items, next))
+}
+
+/// Get the "More" endpoint of a carousel, if it leads to the artist's discography
+fn discography_endpoint(carousel: &Value) -> Option<BrowseEndpoint> {
+    carousel.pointer(CAROUSEL_MORE_ENDPOINT)
+        .filter(|endpoint| endpoint.pointer(BROWSE_PAGE_TYPE).and_then(Value::as_str) == Some("MUSIC_PAGE_TYPE_ARTIST_DISCOGRAPHY"))
+        .and_then(BrowseEndpoint::parse)
+}
+
+/// Read the year of an album or single, whose subtitle is either only the year or "type • year"
+fn product_year(item: &Value) -> Option<String> {
+    iter_from_json(item, TWO_ROW_SUBTITLE).ok()?.last()
+        .and_then(|run| Some(run.pointer(RUN_TEXT)?.as_str()?.to_string()))
+}
+
+/// Whether an item of an artist's carousel is a single or an EP
+///
+/// Singles only show their year, EPs are only recognized by their type with the client's language set to English.
+fn is_single(item: &Value) -> bool {
+    match iter_from_json(item, TWO_ROW_SUBTITLE).map(|runs| runs.as_slice()) {
+        Ok([_year]) => true,
+        Ok([kind, ..]) => matches!(kind.pointer(RUN_TEXT).and_then(Value::as_str), Some("Single" | "EP")),
+        _ => false,
+    }
+}
+
+/// Find the first carousel section whose first item matches the predicate
+///
+/// Sections are recognized by their items throughout the parser, as their titles are localized.
+fn find_carousel<'a>(sections: &[&'a Value], predicate: impl Fn(&Value) -> bool) -> Option<&'a Value> {
+    sections.iter().copied().find(|section| {
+        section.pointer(CAROUSEL_ITEMS).and_then(|items| items.get(0)).is_some_and(&predicate)
+    })
+}
+
+/// Iterate over the text runs in all flex columns of a list item
+fn column_runs(item: &Value) -> impl Iterator<Item = &Value> {
+    iter_from_json(item, SONG_COLUMNS).into_iter().flatten()
+        .filter_map(|column| iter_from_json(column, COLUMN_RUNS).ok())
+        .flatten()
+}
+
+fn continuation_from_json(value: &Value, pointer: &str) -> Option<ContinuationToken> {
+    string_from_json(value, pointer).ok().map(ContinuationToken)
+}
+
 fn value_from_json<'a>(value: &'a Value, pointer: &str) -> Result<&'a Value, ResponseParseError> {
     value.pointer(pointer)
     .ok_or(ResponseParseError::MissingValue(pointer.to_string()))
@@ -195,6 +864,11 @@ fn string_from_json(value: &Value, pointer: &str) -> Result<String, ResponsePars
     }
 }
 
+/// Join the text of all runs into one string
+fn runs_text_from_json(value: &Value, pointer: &str) -> Result<String, ResponseParseError> {
+    Ok(iter_from_json(value, pointer)?.filter_map(|run| run.pointer(RUN_TEXT)?.as_str()).collect())
+}
+
 fn iter_from_json<'a>(value: &'a Value, pointer: &str) -> Result<std::slice::Iter<'a, Value>, ResponseParseError> {
     match value_from_json(value, pointer)?.as_array() {
         Some(it) => Ok(it.iter()),
@@ -202,13 +876,13 @@ fn iter_from_json<'a>(value: &'a Value, pointer: &str) -> Result<std::slice::Ite
     }
 }
 
-fn thumbnails_from_json<'a>(value: &'a Value, pointer: &str) -> Result<Vec<Thumbnail>, ResponseParseError> {
+fn thumbnails_from_json(value: &Value, pointer: &str) -> Result<Vec<Thumbnail>, ResponseParseError> {
     Ok(iter_from_json(value, pointer)?.filter_map(|thumbnail| {
         Some(Thumbnail {
-            url: string_from_json(&thumbnail, THUMBNAIL_URL).ok()?,
+            url: string_from_json(thumbnail, THUMBNAIL_URL).ok()?,
             size: (
-                value_from_json(&thumbnail, THUMBNAIL_WIDTH).ok()?.as_u64()? as usize,
-                value_from_json(&thumbnail, THUMBNAIL_HEIGHT).ok()?.as_u64()? as usize,
+                value_from_json(thumbnail, THUMBNAIL_WIDTH).ok()?.as_u64()? as usize,
+                value_from_json(thumbnail, THUMBNAIL_HEIGHT).ok()?.as_u64()? as usize,
             ),
         })
     }).collect())
@@ -231,4 +905,343 @@ impl Display for ResponseParseError {
         }
     }
 }
-impl Error for ResponseParseError {}
\ No newline at end of file
+impl Error for ResponseParseError {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Browse id of the artist in `res.art.json`
+    const RAMMSTEIN: &str = "UCs6GGpd9zvsYghuYe0VDFUQ";
+
+    fn fixture(name: &str) -> Value {
+        serde_json::from_str(&std::fs::read_to_string(name).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn album_details() {
+        let album = Album::parse(fixture("res.alb.json"), "MPREb_LJSVi8szMQL").unwrap();
+
+        assert_eq!(album.name, "Zeit");
+        assert_eq!(album.browse_id, "MPREb_LJSVi8szMQL");
+        assert_eq!(album.album_type.as_deref(), Some("Album"));
+        assert_eq!(album.playlist_id.as_deref(), Some("OLAK5uy_nl5CYbyn0jhWQiRJ17yvqRDN96eTx7sWQ"));
+        assert_eq!(album.artists, [ArtistRef {
+            name: "Rammstein".to_string(),
+            browse_id: Some("UCs6GGpd9zvsYghuYe0VDFUQ".to_string()),
+        }]);
+        assert_eq!(album.year, "2022");
+        assert_eq!(album.track_count, Some(11));
+        assert_eq!(album.duration.as_deref(), Some("44 minutes"));
+
+        let description = album.description.unwrap();
+        assert!(description.text.starts_with("Zeit is the eighth studio album"));
+        assert!(description.text.ends_with("released as the lead single on 10 March 2022."));
+        let attribution = description.attribution.unwrap();
+        assert_eq!(attribution.text, "From Wikipedia (https://en.wikipedia.org/wiki/Zeit_(Rammstein_album)) \
+            under Creative Commons Attribution CC-BY-SA 3.0 (https://creativecommons.org/licenses/by-sa/3.0/legalcode)");
+        assert_eq!(attribution.source_url.as_deref(), Some("https://en.wikipedia.org/wiki/Zeit_(Rammstein_album)"));
+        assert_eq!(attribution.license_url.as_deref(), Some("https://creativecommons.org/licenses/by-sa/3.0/legalcode"));
+    }
+
+    #[test]
+    fn description_with_empty_run() {
+        let runs = serde_json::json!([
+            {"text": "An album.\n\nFrom Wikipedia ("},
+            {},
+            {"text": "en.wikipedia.org/...", "navigationEndpoint": {"urlEndpoint": {"url": "https://en.wikipedia.org/wiki/Zeit"}}},
+            {"text": ")"},
+        ]);
+        let description = Description::parse(runs.as_array().unwrap().iter());
+
+        assert_eq!(description.text, "An album.");
+        let attribution = description.attribution.unwrap();
+        assert_eq!(attribution.text, "From Wikipedia (https://en.wikipedia.org/wiki/Zeit)");
+        assert_eq!(attribution.source_url.as_deref(), Some("https://en.wikipedia.org/wiki/Zeit"));
+        assert_eq!(attribution.license_url, None);
+    }
+
+    #[test]
+    fn album_tracks() {
+        let album = Album::parse(fixture("res.alb.json"), "MPREb_LJSVi8szMQL").unwrap();
+
+        assert_eq!(album.tracks.len(), 11);
+        assert!(album.tracks.iter().all(|track| track.disc_number == 1));
+        assert!(album.other_versions.is_empty());
+        let track = &album.tracks[2];
+        assert_eq!(track.name, "Schwarz");
+        assert_eq!(track.track_num, 3);
+        assert_eq!(track.duration, Some(Duration::from_secs(4 * 60 + 19)));
+        assert_eq!(track.plays, Some(7_100_000));
+        assert!(track.explicit);
+        assert!(track.available);
+        assert!(track.artists.is_empty());
+        assert!(!album.tracks[0].explicit);
+        assert_eq!(album.tracks[8].plays, Some(103_000_000));
+    }
+
+    #[test]
+    fn unavailable_album_track() {
+        let mut res = fixture("res.alb.json");
+        let track = &mut res.pointer_mut(&format!("{ALBUM_SECTIONS}/0{ALBUM_SECTION_TRACKS}/3")).unwrap()["musicResponsiveListItemRenderer"];
+        track["musicItemRendererDisplayPolicy"] = Value::from("MUSIC_ITEM_RENDERER_DISPLAY_POLICY_GREY_OUT");
+        track.as_object_mut().unwrap().remove("playlistItemData");
+        track["flexColumns"][0]["musicResponsiveListItemFlexColumnRenderer"]["text"]["runs"][0]
+            .as_object_mut().unwrap().remove("navigationEndpoint");
+        let album = Album::parse(res, "MPREb_LJSVi8szMQL").unwrap();
+
+        assert_eq!(album.tracks.len(), 11);
+        assert_eq!(album.tracks[3].video_id, None);
+        assert!(!album.tracks[3].available);
+        assert_eq!(album.tracks[3].track_num, 4);
+        assert_eq!(album.tracks[4].track_num, 5);
+        assert!(album.tracks[4].video_id.is_some());
+        assert!(album.tracks[4].available);
+    }
+
+    #[test]
+    fn album_browse_id_in_html() {
+        let html = r#"<script>ytcfg.set({});var a = '\x22browseId\x22:\x22MPREb_LJSVi8szMQL\x22,\x22';</script>"#;
+        assert_eq!(album_browse_id_from_html(html).as_deref(), Some("MPREb_LJSVi8szMQL"));
+        assert_eq!(album_browse_id_from_html("<html></html>"), None);
+    }
+
+    #[test]
+    fn multi_disc_album() {
+        let mut res = fixture("res.alb.json");
+        let sections = res.pointer_mut(ALBUM_SECTIONS).unwrap().as_array_mut().unwrap();
+        let disc = sections[0].clone();
+        sections.push(disc);
+        sections.push(serde_json::json!({"musicCarouselShelfRenderer": {"contents": [{"musicTwoRowItemRenderer": {
+            "title": {"runs": [{"text": "Zeit (Deluxe)"}]},
+            "navigationEndpoint": {"browseEndpoint": {
+                "browseId": "MPREb_deluxe",
+                "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": "MUSIC_PAGE_TYPE_ALBUM"}},
+            }},
+        }}]}}));
+        let album = Album::parse(res, "MPREb_LJSVi8szMQL").unwrap();
+
+        assert_eq!(album.tracks.len(), 22);
+        assert_eq!(album.tracks[10].disc_number, 1);
+        assert_eq!(album.tracks[11].disc_number, 2);
+        assert_eq!(album.tracks[11].track_num, 1);
+        assert_eq!(album.other_versions, [AlbumRef {
+            name: "Zeit (Deluxe)".to_string(),
+            browse_id: "MPREb_deluxe".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn counts_and_durations() {
+        assert_eq!(count_from_text("11 songs"), Some(11));
+        assert_eq!(count_from_text("1,234 songs"), Some(1234));
+        assert_eq!(count_from_text("8M plays"), Some(8_000_000));
+        assert_eq!(count_from_text("1.23K views"), Some(1230));
+        assert_eq!(duration_from_text("3:09"), Some(Duration::from_secs(189)));
+        assert_eq!(duration_from_text("1:02:03"), Some(Duration::from_secs(3723)));
+        assert_eq!(duration_from_text("soon"), None);
+    }
+
+    #[test]
+    fn artist_header() {
+        let artist = Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.name, "Rammstein");
+        assert!(artist.description.unwrap().starts_with("Rammstein is a German Neue Deutsche Härte band"));
+        assert_eq!(artist.subscribers, Some(8_170_000));
+        assert_eq!(artist.views, Some(5_992_409_945));
+        assert_eq!(artist.channel_id.as_deref(), Some("UCYp3rk70ACGXQ4gFAiMr1SQ"));
+        assert_eq!(artist.shuffle_playlist_id.as_deref(), Some("RDAONfxU2Vk5xtTODj0ixpqOsQ"));
+        assert_eq!(artist.radio_playlist_id.as_deref(), Some("RDEMNfxU2Vk5xtTODj0ixpqOsQ"));
+        assert!(!artist.thumbnails.is_empty());
+    }
+
+    #[test]
+    fn artist_discography_endpoints() {
+        let artist = Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.albums_endpoint, Some(BrowseEndpoint {
+            browse_id: "MPADUCs6GGpd9zvsYghuYe0VDFUQ".to_string(),
+            params: Some("ggMIegYIARoCAQI%3D".to_string()),
+        }));
+        assert_eq!(artist.singles_endpoint, None);
+    }
+
+    #[test]
+    fn artist_without_albums() {
+        let mut res = fixture("res.art.json");
+        let sections = res.pointer_mut(ARTIST_SECTIONS).unwrap().as_array_mut().unwrap();
+        sections.remove(1);
+        let artist = Artist::parse(res, RAMMSTEIN).unwrap();
+
+        assert!(artist.albums.is_empty());
+        assert_eq!(artist.albums_endpoint, None);
+        assert_eq!(artist.singles.len(), Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap().singles.len());
+        assert_eq!(artist.singles_endpoint, None);
+        assert_eq!(artist.videos_browse_id.as_deref(), Some("VLOLAK5uy_lOwjigt2HYhaCb3-54N3rGVvwM-7UKkqI"));
+    }
+
+    /// Turn the first single of the fixture into an EP, which shows its type like albums do
+    fn with_ep_first(res: &mut Value) {
+        let sections = res.pointer_mut(ARTIST_SECTIONS).unwrap();
+        sections[2]["musicCarouselShelfRenderer"]["contents"][0]["musicTwoRowItemRenderer"]["subtitle"]["runs"] =
+            serde_json::json!([{"text": "EP"}, {"text": " • "}, {"text": "2019"}]);
+    }
+
+    #[test]
+    fn artist_singles_with_ep_first() {
+        let mut res = fixture("res.art.json");
+        with_ep_first(&mut res);
+        let artist = Artist::parse(res, RAMMSTEIN).unwrap();
+        let original = Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.albums.len(), original.albums.len());
+        assert_eq!(artist.singles.len(), original.singles.len());
+        assert_eq!(artist.singles[0].year, "2019");
+    }
+
+    #[test]
+    fn artist_with_only_eps_and_singles() {
+        let mut res = fixture("res.art.json");
+        with_ep_first(&mut res);
+        res.pointer_mut(ARTIST_SECTIONS).unwrap().as_array_mut().unwrap().remove(1);
+        let artist = Artist::parse(res, RAMMSTEIN).unwrap();
+
+        assert!(artist.albums.is_empty());
+        assert_eq!(artist.singles.len(), Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap().singles.len());
+        assert_eq!(artist.singles[0].year, "2019");
+    }
+
+    #[test]
+    fn artist_product_playlist_ids() {
+        let artist = Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.albums[0].playlist_id.as_deref(), Some("OLAK5uy_nl5CYbyn0jhWQiRJ17yvqRDN96eTx7sWQ"));
+    }
+
+    #[test]
+    fn discography_page() {
+        let item = serde_json::json!({"musicTwoRowItemRenderer": {
+            "title": {"runs": [{"text": "Zeit"}]},
+            "subtitle": {"runs": [{"text": "Album"}, {"text": " • "}, {"text": "2022"}]},
+            "navigationEndpoint": {"browseEndpoint": {"browseId": "MPREb_zeit"}},
+        }});
+        let res = serde_json::json!({"contents": {"singleColumnBrowseResultsRenderer": {"tabs": [{"tabRenderer": {"content": {
+            "sectionListRenderer": {"contents": [{"gridRenderer": {
+                "items": [item],
+                "continuations": [{"nextContinuationData": {"continuation": "next"}}],
+            }}]},
+        }}}]}}});
+        let (albums, next) = Product::parse_discography_page(res).unwrap();
+
+        assert_eq!(albums[0].name, "Zeit");
+        assert_eq!(albums[0].year, "2022");
+        assert_eq!(albums[0].browse_id, "MPREb_zeit");
+        assert_eq!(next, Some(ContinuationToken("next".to_string())));
+
+        let res = serde_json::json!({"continuationContents": {"gridContinuation": {"items": [item]}}});
+        let (albums, next) = Product::parse_discography_page(res).unwrap();
+        assert_eq!(albums.len(), 1);
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn artist_top_songs() {
+        let artist = Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.top_songs.len(), 5);
+        assert_eq!(artist.top_songs[0].name, "Sonne");
+        assert_eq!(artist.top_songs[0].video_id, "KUZ7jG7BKE8");
+        assert_eq!(artist.top_songs[0].plays, Some(453_000_000));
+        assert_eq!(artist.top_songs[0].artists[0].name, "Rammstein");
+        assert_eq!(artist.top_songs[0].album, Some(AlbumRef {
+            name: "Mutter".to_string(),
+            browse_id: "MPREb_KtV3PgMEVJL".to_string(),
+        }));
+        assert_eq!(artist.top_songs_browse_id.as_deref(), Some("VLOLAK5uy_kSiNrxH1LMHq_1N0Y0Wu0Y-CpNeOggPYs"));
+    }
+
+    #[test]
+    fn artist_videos() {
+        let artist = Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.videos.len(), 10);
+        assert_eq!(artist.videos[0].name, "Rammstein - Du Hast (Official 4K Video)");
+        assert_eq!(artist.videos[0].video_id, "W3q8Od5qJio");
+        assert_eq!(artist.videos[0].views, Some(581_000_000));
+        assert_eq!(artist.videos[0].video_type, Some(MusicVideoType::Omv));
+        assert_eq!(artist.videos_browse_id.as_deref(), Some("VLOLAK5uy_lOwjigt2HYhaCb3-54N3rGVvwM-7UKkqI"));
+    }
+
+    #[test]
+    fn related_artists() {
+        let artist = Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.related.len(), 10);
+        assert_eq!(artist.related[0].name, "Lindemann");
+        assert_eq!(artist.related[0].subs, "1.23M subscribers");
+        assert_eq!(artist.related[0].browse_id, "UCmxYswrey9UDJyPDadspGBQ");
+    }
+
+    #[test]
+    fn artist_playlists() {
+        let artist = Artist::parse(fixture("res.art.json"), RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.featured_on.len(), 10);
+        assert_eq!(artist.featured_on[0].title, "Presenting Rammstein");
+        assert_eq!(artist.featured_on[0].browse_id, "VLRDCLAK5uy_nP1U38z5TIWBgTTiXDBgO_3DaHSaotjbc");
+        assert_eq!(artist.featured_on[0].playlist_id, "RDCLAK5uy_nP1U38z5TIWBgTTiXDBgO_3DaHSaotjbc");
+        assert_eq!(artist.featured_on[0].author.as_deref(), Some("YouTube Music"));
+        assert!(artist.playlists.is_empty());
+    }
+
+    #[test]
+    fn artist_own_playlists() {
+        let mut res = fixture("res.art.json");
+        let featured = res.pointer_mut(&format!("{ARTIST_SECTIONS}/4{CAROUSEL_ITEMS}")).unwrap().as_array_mut().unwrap();
+        featured.push(serde_json::json!({"musicTwoRowItemRenderer": {
+            "title": {"runs": [{"text": "Rammstein Live"}]},
+            "subtitle": {"runs": [
+                {"text": "Playlist"},
+                {"text": " • "},
+                {"text": "Rammstein", "navigationEndpoint": {"browseEndpoint": {"browseId": "UCYp3rk70ACGXQ4gFAiMr1SQ"}}},
+            ]},
+            "navigationEndpoint": {"browseEndpoint": {
+                "browseId": "VLPLlive",
+                "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": "MUSIC_PAGE_TYPE_PLAYLIST"}},
+            }},
+        }}));
+        let artist = Artist::parse(res, RAMMSTEIN).unwrap();
+
+        assert_eq!(artist.featured_on.len(), 10);
+        assert_eq!(artist.playlists.len(), 1);
+        assert_eq!(artist.playlists[0].title, "Rammstein Live");
+        assert_eq!(artist.playlists[0].author.as_deref(), Some("Rammstein"));
+    }
+
+    #[test]
+    fn search_page() {
+        let page = ArtistSearchResult::parse(fixture("res.search.json")).unwrap();
+        assert_eq!(page.items.len(), 20);
+        assert_eq!(page.items[0].name, "Rammstein");
+        assert_eq!(page.items[0].browse_id, "UCs6GGpd9zvsYghuYe0VDFUQ");
+        assert!(page.next.is_some());
+        assert_eq!(page.corrected_query, None);
+    }
+
+    #[test]
+    fn corrected_search_page() {
+        let mut res = fixture("res.search.json");
+        let sections = res.pointer_mut(SEARCH_SECTIONS).unwrap().as_array_mut().unwrap();
+        sections.insert(0, serde_json::json!({"itemSectionRenderer": {"contents": [{"showingResultsForRenderer": {
+            "showingResultsFor": {"runs": [{"text": "Showing results for "}]},
+            "correctedQuery": {"runs": [{"text": "Ramm", "italics": true}, {"text": "stein"}]},
+            "searchInsteadFor": {"runs": [{"text": "Search instead for "}]},
+            "originalQuery": {"runs": [{"text": "Ramstein"}]},
+        }}]}}));
+        let page = ArtistSearchResult::parse(res).unwrap();
+
+        assert_eq!(page.items[0].name, "Rammstein");
+        assert_eq!(page.corrected_query.as_deref(), Some("Rammstein"));
+        assert_eq!(page.original_query.as_deref(), Some("Ramstein"));
+    }
+}
@@ -0,0 +1,174 @@
+use serde_json::Value;
+
+use super::*;
+
+/// Youtube Music's charts of one country
+#[derive(Debug, Clone)]
+pub struct Charts {
+    /// Name of the country the charts are from, such as "Global"
+    pub country: Option<String>,
+    /// Codes of the countries charts are available for, such as "ZZ" for global charts
+    pub countries: Vec<String>,
+    pub songs: Vec<ChartEntry<ArtistSong>>,
+    /// Video chart playlists, or single videos in older layouts of the page
+    pub videos: Vec<ChartEntry<HomeItem>>,
+    pub artists: Vec<ChartEntry<ArtistSearchResult>>,
+    pub trending: Vec<ChartEntry<ArtistSong>>,
+}
+
+/// An item of a chart with its position
+#[derive(Debug, Clone)]
+pub struct ChartEntry<T> {
+    /// Missing for charts without positions, such as the video chart playlists
+    pub rank: Option<usize>,
+    pub trend: Option<Trend>,
+    pub item: T,
+}
+
+/// How an item moved since the last chart
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Trend {
+    Up,
+    Down,
+    Neutral,
+}
+
+impl Charts {
+    /// Tell the charts apart by their items
+    pub(crate) fn parse(res: Value) -> Result<Self, ResponseParseError> {
+        let sections: Vec<&Value> = iter_from_json(value_from_json(&res, BROWSE_SECTION_LIST)?, SHELF_ITEMS)?.collect();
+        let mut charts = Charts {
+            country: sections.iter().find_map(|section| string_from_json(section, CHART_COUNTRY).ok()),
+            countries: iter_from_json(&res, CHART_COUNTRY_OPTIONS).into_iter().flatten()
+                .filter_map(|mutation| string_from_json(mutation, CHART_COUNTRY_OPTION).ok())
+                .collect(),
+            songs: Vec::new(),
+            videos: Vec::new(),
+            artists: Vec::new(),
+            trending: Vec::new(),
+        };
+
+        for items in sections.iter().filter_map(|section| iter_from_json(section, CAROUSEL_ITEMS).ok()) {
+            let items: Vec<&Value> = items.collect();
+            let Some(first) = items.first() else {
+                continue;
+            };
+            if first.pointer(CHART_ARTIST_PAGE_TYPE).and_then(Value::as_str) == Some("MUSIC_PAGE_TYPE_ARTIST") {
+                if charts.artists.is_empty() {
+                    charts.artists = ChartEntry::parse_all(&items, parse_artist);
+                }
+            } else if first.pointer(SONG_COLUMNS).is_some() {
+                // trending songs come in the same form as the top songs, after them
+                let songs = ChartEntry::parse_all(&items, ArtistSong::parse);
+                if charts.songs.is_empty() {
+                    charts.songs = songs;
+                } else if charts.trending.is_empty() {
+                    charts.trending = songs;
+                }
+            } else if charts.videos.is_empty() && items.iter().all(|item| is_video_chart(item)) {
+                // the video charts come before playlists of other kinds, such as genres
+                charts.videos = ChartEntry::parse_all(&items, HomeItem::parse);
+            }
+        }
+        Ok(charts)
+    }
+}
+
+impl<T> ChartEntry<T> {
+    fn parse_all(items: &[&Value], parse: fn(&Value) -> Option<T>) -> Vec<Self> {
+        items.iter().filter_map(|item| {
+            Some(ChartEntry {
+                rank: string_from_json(item, CHART_RANK).ok().and_then(|it| it.parse().ok()),
+                trend: item.pointer(CHART_TREND).and_then(Value::as_str).and_then(|icon| match icon {
+                    "ARROW_DROP_UP" => Some(Trend::Up),
+                    "ARROW_DROP_DOWN" => Some(Trend::Down),
+                    "ARROW_CHART_NEUTRAL" => Some(Trend::Neutral),
+                    _ => None,
+                }),
+                item: parse(item)?,
+            })
+        }).collect()
+    }
+}
+
+/// Whether an item is a video or a video chart playlist
+fn is_video_chart(item: &Value) -> bool {
+    item.pointer(TWO_ROW_WATCH).is_some()
+        || item.pointer(TWO_ROW_PAGE_TYPE).and_then(Value::as_str) == Some("MUSIC_PAGE_TYPE_PLAYLIST")
+}
+
+/// Parse an artist from a chart, where their subscribers are the whole second column
+fn parse_artist(item: &Value) -> Option<ArtistSearchResult> {
+    Some(ArtistSearchResult {
+        name: string_from_json(item, SEARCHED_ARTIST_NAME).ok()?,
+        subs: runs_text_from_json(item, CHART_ARTIST_SUBS).ok()?,
+        browse_id: string_from_json(item, SEARCHED_ARTIST_ID).ok()?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::parse::fixtures::{browse_sections, carousel, text_column, two_row};
+
+    fn ranked(rank: &str, trend: &str, columns: Value, endpoint: Value) -> Value {
+        json!({"musicResponsiveListItemRenderer": {
+            "flexColumns": columns,
+            "navigationEndpoint": endpoint,
+            "customIndexColumn": {"musicCustomIndexColumnRenderer": {
+                "text": {"runs": [{"text": rank}]},
+                "icon": {"iconType": trend},
+            }},
+        }})
+    }
+
+    #[test]
+    fn charts() {
+        let mut song = ranked("1", "ARROW_DROP_UP", json!([text_column("Sonne"), text_column("Rammstein")]), json!({}));
+        song["musicResponsiveListItemRenderer"]["playlistItemData"] = json!({"videoId": "KUZ7jG7BKE8"});
+        let artist = ranked("2", "ARROW_CHART_NEUTRAL", json!([text_column("Rammstein"), text_column("7.1M subscribers")]), json!({"browseEndpoint": {
+            "browseId": "UCs6GGpd9zvsYghuYe0VDFUQ",
+            "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": "MUSIC_PAGE_TYPE_ARTIST"}},
+        }}));
+        let artist_of_other_chart = ranked("1", "ARROW_DROP_UP", json!([text_column("Ghost"), text_column("2M subscribers")]), artist["musicResponsiveListItemRenderer"]["navigationEndpoint"].clone());
+        let playlist = two_row("Top 100 Music Videos Global", "YouTube Music", "VLPL4fGSI1pDJn6puJdseH2Rt9sMvt9E2M4i", "MUSIC_PAGE_TYPE_PLAYLIST");
+        let album = two_row("Mutter", "Rammstein", "MPREb_ndGIy2ttHWD", "MUSIC_PAGE_TYPE_ALBUM");
+        let genre = two_row("Metal", "YouTube Music", "VLPL4fGSI1pDJn4Rs-d0Gbg5BhwqOeU0VAkN", "MUSIC_PAGE_TYPE_PLAYLIST");
+        let mut res = browse_sections(json!([
+            {"musicShelfRenderer": {"subheaders": [{"musicSideAlignedItemRenderer": {"startItems": [{"musicSortFilterButtonRenderer": {
+                "title": {"runs": [{"text": "Global"}]},
+            }}]}}]}},
+            carousel("Top songs", json!([song.clone()])),
+            carousel("New albums", json!([album])),
+            carousel("Video charts", json!([playlist])),
+            carousel("Top artists", json!([artist.clone()])),
+            carousel("Trending", json!([song.clone()])),
+            carousel("Genres", json!([genre])),
+            carousel("Top artists", json!([artist, artist_of_other_chart])),
+            carousel("Trending", json!([song])),
+        ]));
+        res["frameworkUpdates"] = json!({"entityBatchUpdate": {"mutations": [
+            {"payload": {"musicFormBooleanChoice": {"opaqueToken": "ZZ"}}},
+            {"payload": {"musicFormBooleanChoice": {"opaqueToken": "CZ"}}},
+            {"payload": {"musicForm": {}}},
+        ]}});
+        let charts = Charts::parse(res).unwrap();
+
+        assert_eq!(charts.country.as_deref(), Some("Global"));
+        assert_eq!(charts.countries, vec!["ZZ", "CZ"]);
+        assert_eq!(charts.songs[0].rank, Some(1));
+        assert_eq!(charts.songs[0].trend, Some(Trend::Up));
+        assert_eq!(charts.songs[0].item.name, "Sonne");
+        assert_eq!(charts.songs.len(), 1);
+        assert_eq!(charts.trending.len(), 1);
+        assert_eq!(charts.videos.len(), 1);
+        assert!(matches!(&charts.videos[0].item, HomeItem::Playlist(playlist) if playlist.playlist_id == "PL4fGSI1pDJn6puJdseH2Rt9sMvt9E2M4i"));
+        assert_eq!(charts.videos[0].rank, None);
+        assert_eq!(charts.artists.len(), 1);
+        assert_eq!(charts.artists[0].rank, Some(2));
+        assert_eq!(charts.artists[0].trend, Some(Trend::Neutral));
+        assert_eq!(charts.artists[0].item.subs, "7.1M subscribers");
+    }
+}
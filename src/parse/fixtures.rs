@@ -0,0 +1,39 @@
+//! Builders for the json fragments that make up the test responses
+
+use serde_json::{json, Value};
+
+/// A browse response with the given sections
+pub(crate) fn browse_sections(contents: Value) -> Value {
+    json!({"contents": {"singleColumnBrowseResultsRenderer": {"tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {
+        "contents": contents,
+    }}}}]}}})
+}
+
+pub(crate) fn carousel(title: &str, items: Value) -> Value {
+    json!({"musicCarouselShelfRenderer": {
+        "header": {"musicCarouselShelfBasicHeaderRenderer": {"title": {"runs": [{"text": title}]}}},
+        "contents": items,
+    }})
+}
+
+/// A carousel item linking to a page of the given type
+pub(crate) fn two_row(title: &str, subtitle: &str, browse_id: &str, page_type: &str) -> Value {
+    json!({"musicTwoRowItemRenderer": {
+        "title": {"runs": [{"text": title}]},
+        "subtitle": {"runs": [{"text": subtitle}]},
+        "navigationEndpoint": {"browseEndpoint": {
+            "browseId": browse_id,
+            "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": page_type}},
+        }},
+    }})
+}
+
+/// A flex column of a list item
+pub(crate) fn column(runs: Value) -> Value {
+    json!({"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": runs}}})
+}
+
+/// A flex column with a single run of plain text
+pub(crate) fn text_column(text: &str) -> Value {
+    column(json!([{"text": text}]))
+}
@@ -0,0 +1,100 @@
+use serde_json::Value;
+
+use super::*;
+
+/// A titled carousel or grid of the home feed or the explore pages
+#[derive(Debug, Clone)]
+pub struct HomeShelf {
+    /// Such as "Quick picks" or "Trending"
+    pub title: String,
+    pub items: Vec<HomeItem>,
+}
+
+/// An item of a shelf, which can hold any kind of item
+#[derive(Debug, Clone)]
+pub enum HomeItem {
+    Song(ArtistSong),
+    Video(ArtistVideo),
+    Album(AlbumRef),
+    Playlist(PlaylistRef),
+    Artist(ArtistSearchResult),
+}
+
+impl HomeShelf {
+    /// Parse from either a carousel or a grid
+    pub(crate) fn parse(section: &Value) -> Option<Self> {
+        let (title, items) = match section.pointer(GRID_SECTION_ITEMS) {
+            Some(_) => (GRID_SECTION_TITLE, GRID_SECTION_ITEMS),
+            None => (CAROUSEL_TITLE, CAROUSEL_ITEMS),
+        };
+        Some(HomeShelf {
+            title: string_from_json(section, title).ok()?,
+            items: iter_from_json(section, items).ok()?.filter_map(HomeItem::parse).collect(),
+        })
+    }
+
+    /// Parse the shelves of either the first page or a continuation, skipping sections of other kinds
+    pub(crate) fn parse_page(res: Value) -> Result<Continued<Self>, ResponseParseError> {
+        let (sections, next) = shelf_page(&res, BROWSE_SECTION_LIST, CONTINUED_SECTION_LIST, SHELF_ITEMS)?;
+        Ok((sections.into_iter().filter_map(Self::parse).collect(), next))
+    }
+}
+
+impl HomeItem {
+    pub(crate) fn parse(item: &Value) -> Option<Self> {
+        if item.pointer(SONG_COLUMNS).is_some() {
+            return ArtistSong::parse(item).map(HomeItem::Song);
+        }
+        if item.pointer(TWO_ROW_WATCH).is_some() {
+            return ArtistVideo::parse(item).map(HomeItem::Video);
+        }
+        match item.pointer(TWO_ROW_PAGE_TYPE)?.as_str()? {
+            "MUSIC_PAGE_TYPE_ALBUM" => AlbumRef::parse_two_row(item).map(HomeItem::Album),
+            "MUSIC_PAGE_TYPE_PLAYLIST" => PlaylistRef::parse(item).map(HomeItem::Playlist),
+            "MUSIC_PAGE_TYPE_ARTIST" => ArtistSearchResult::parse_related(item).map(HomeItem::Artist),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::parse::fixtures::{carousel, two_row};
+
+    #[test]
+    fn home_shelves() {
+        let res = json!({"contents": {"singleColumnBrowseResultsRenderer": {"tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {
+            "contents": [
+                carousel("Albums for you", json!([
+                    two_row("Zeit", "Rammstein", "MPREb_LJSVi8szMQL", "MUSIC_PAGE_TYPE_ALBUM"),
+                    two_row("Rammstein", "Rammstein", "UCs6GGpd9zvsYghuYe0VDFUQ", "MUSIC_PAGE_TYPE_ARTIST"),
+                    two_row("Presenting Rammstein", "Rammstein", "VLRDCLAK5uy_nP1U38z5TIWBgTTiXDBgO_3DaHSaotjbc", "MUSIC_PAGE_TYPE_PLAYLIST"),
+                ])),
+                {"musicTastebuilderShelfRenderer": {}},
+            ],
+            "continuations": [{"nextContinuationData": {"continuation": "more shelves"}}],
+        }}}}]}}});
+        let (shelves, next) = HomeShelf::parse_page(res).unwrap();
+
+        assert_eq!(shelves.len(), 1);
+        assert_eq!(shelves[0].title, "Albums for you");
+        assert!(matches!(&shelves[0].items[0], HomeItem::Album(album) if album.browse_id == "MPREb_LJSVi8szMQL"));
+        assert!(matches!(&shelves[0].items[1], HomeItem::Artist(artist) if artist.name == "Rammstein"));
+        assert!(matches!(&shelves[0].items[2], HomeItem::Playlist(playlist) if playlist.title == "Presenting Rammstein"));
+        assert_eq!(next, Some(ContinuationToken("more shelves".to_string())));
+
+        let grid = json!({"gridRenderer": {
+            "header": {"gridHeaderRenderer": {"title": {"runs": [{"text": "New albums & singles"}]}}},
+            "items": [two_row("Zeit", "Rammstein", "MPREb_LJSVi8szMQL", "MUSIC_PAGE_TYPE_ALBUM")],
+        }});
+        let shelf = HomeShelf::parse(&grid).unwrap();
+        assert_eq!(shelf.title, "New albums & singles");
+        assert_eq!(shelf.items.len(), 1);
+
+        let res = json!({"continuationContents": {"sectionListContinuation": {"contents": []}}});
+        assert_eq!(HomeShelf::parse_page(res).unwrap().1, None);
+    }
+}
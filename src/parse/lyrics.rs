@@ -0,0 +1,72 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use super::*;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lyrics {
+    pub text: String,
+    /// Such as "Source: LyricFind", which should be displayed along with the lyrics
+    pub source: Option<String>,
+    /// Lines with the time they start to be sung at, if Youtube has them
+    pub synced: Option<Vec<(Duration, String)>>,
+}
+
+impl Lyrics {
+    /// Parse from the mobile client's response, which has timed lyrics when they are available
+    pub(crate) fn parse(res: Value) -> Result<Self, ResponseParseError> {
+        if let Some(timed) = res.pointer(TIMED_LYRICS) {
+            let synced: Vec<(Duration, String)> = iter_from_json(timed, TIMED_LYRICS_LINES)?.filter_map(|line| {
+                // the start is sent as a string, but a number is accepted as well
+                let start = line.pointer(TIMED_LYRICS_START)?;
+                let start = start.as_u64().or_else(|| start.as_str()?.parse().ok())?;
+                Some((Duration::from_millis(start), string_from_json(line, TIMED_LYRICS_LINE).ok()?))
+            }).collect();
+
+            return Ok(Lyrics {
+                text: synced.iter().map(|(_, line)| line.as_str()).collect::<Vec<_>>().join("\n"),
+                source: string_from_json(timed, TIMED_LYRICS_SOURCE).ok(),
+                synced: Some(synced),
+            });
+        }
+
+        let shelf = value_from_json(&res, LYRICS_SHELF)?;
+        Ok(Lyrics {
+            text: runs_text_from_json(shelf, LYRICS_TEXT)?,
+            source: string_from_json(shelf, LYRICS_SOURCE).ok(),
+            synced: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn plain_and_timed_lyrics() {
+        let res = json!({"contents": {"sectionListRenderer": {"contents": [{"musicDescriptionShelfRenderer": {
+            "description": {"runs": [{"text": "Alle warten auf das Licht\nFürchtet euch, fürchtet euch nicht"}]},
+            "footer": {"runs": [{"text": "Source: LyricFind"}]},
+        }}]}}});
+        let lyrics = Lyrics::parse(res).unwrap();
+        assert_eq!(lyrics.text, "Alle warten auf das Licht\nFürchtet euch, fürchtet euch nicht");
+        assert_eq!(lyrics.source.as_deref(), Some("Source: LyricFind"));
+        assert_eq!(lyrics.synced, None);
+
+        let res = json!({"contents": {"elementRenderer": {"newElement": {"type": {"componentType": {"model": {"timedLyricsModel": {"lyricsData": {
+            "timedLyricsData": [
+                {"lyricLine": "Alle warten auf das Licht", "cueRange": {"startTimeMilliseconds": "26470", "endTimeMilliseconds": "29120"}},
+                {"lyricLine": "Fürchtet euch, fürchtet euch nicht", "cueRange": {"startTimeMilliseconds": 29120, "endTimeMilliseconds": "31800"}},
+            ],
+            "sourceMessage": "Source: LyricFind",
+        }}}}}}}}});
+        let lyrics = Lyrics::parse(res).unwrap();
+        assert_eq!(lyrics.text, "Alle warten auf das Licht\nFürchtet euch, fürchtet euch nicht");
+        assert_eq!(lyrics.source.as_deref(), Some("Source: LyricFind"));
+        assert_eq!(lyrics.synced.unwrap()[1], (Duration::from_millis(29120), "Fürchtet euch, fürchtet euch nicht".to_string()));
+    }
+}
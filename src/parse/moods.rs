@@ -0,0 +1,88 @@
+use serde_json::Value;
+
+use super::*;
+
+/// A group of the moods & genres page, such as "Moods & moments" or "Genres"
+#[derive(Debug, Clone)]
+pub struct MoodSection {
+    pub title: String,
+    pub categories: Vec<MoodCategory>,
+}
+
+/// A mood or genre, whose playlists can be requested by its params
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MoodCategory {
+    pub title: String,
+    pub params: String,
+}
+
+impl MoodSection {
+    pub(crate) fn parse_all(res: Value) -> Result<Vec<Self>, ResponseParseError> {
+        let sections = iter_from_json(value_from_json(&res, BROWSE_SECTION_LIST)?, SHELF_ITEMS)?;
+        Ok(sections.filter_map(|section| {
+            Some(MoodSection {
+                title: string_from_json(section, GRID_SECTION_TITLE).ok()?,
+                categories: iter_from_json(section, GRID_SECTION_ITEMS).ok()?.filter_map(|item| {
+                    Some(MoodCategory {
+                        title: string_from_json(item, NAVIGATION_BUTTON_TITLE).ok()?,
+                        params: string_from_json(item, NAVIGATION_BUTTON_PARAMS).ok()?,
+                    })
+                }).collect(),
+            })
+        }).collect())
+    }
+}
+
+/// Parse the playlists of all sections of a mood or genre, which can be grids or carousels
+pub(crate) fn mood_playlists(res: Value) -> Result<Vec<PlaylistRef>, ResponseParseError> {
+    let sections = iter_from_json(value_from_json(&res, BROWSE_SECTION_LIST)?, SHELF_ITEMS)?;
+    Ok(sections
+        .filter_map(|section| [GRID_SECTION_ITEMS, CAROUSEL_ITEMS, IMMERSIVE_CAROUSEL_ITEMS].iter()
+            .find_map(|pointer| iter_from_json(section, pointer).ok()))
+        .flatten()
+        .filter_map(PlaylistRef::parse)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::parse::fixtures::{browse_sections, two_row};
+
+    fn playlist(title: &str) -> Value {
+        two_row(title, "YouTube Music", &format!("VL{title}"), "MUSIC_PAGE_TYPE_PLAYLIST")
+    }
+
+    #[test]
+    fn mood_categories() {
+        let res = browse_sections(json!([{"gridRenderer": {
+            "header": {"gridHeaderRenderer": {"title": {"runs": [{"text": "Genres"}]}}},
+            "items": [{"musicNavigationButtonRenderer": {
+                "buttonText": {"runs": [{"text": "Rock"}]},
+                "clickCommand": {"browseEndpoint": {"browseId": "FEmusic_moods_and_genres_category", "params": "ggMPOg1uX1BmNzc2V2p0YXJ5"}},
+            }}],
+        }}]));
+        let sections = MoodSection::parse_all(res).unwrap();
+
+        assert_eq!(sections[0].title, "Genres");
+        assert_eq!(sections[0].categories, vec![MoodCategory {
+            title: "Rock".to_string(),
+            params: "ggMPOg1uX1BmNzc2V2p0YXJ5".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn mood_playlists_of_all_sections() {
+        let res = browse_sections(json!([
+            {"musicImmersiveCarouselShelfRenderer": {"contents": [playlist("Rock Classics")]}},
+            {"musicCarouselShelfRenderer": {"contents": [playlist("Hard Rock")]}},
+            {"gridRenderer": {"items": [playlist("Indie Rock"), playlist("Punk")]}},
+        ]));
+        let playlists = mood_playlists(res).unwrap();
+
+        let titles: Vec<&str> = playlists.iter().map(|playlist| playlist.title.as_str()).collect();
+        assert_eq!(titles, vec!["Rock Classics", "Hard Rock", "Indie Rock", "Punk"]);
+    }
+}
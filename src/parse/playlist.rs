@@ -0,0 +1,215 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use super::*;
+
+#[derive(Debug, Clone)]
+pub struct Playlist {
+    /// The playlist id, without the `VL` prefix of its browse id
+    pub id: String,
+    pub title: String,
+    pub author: Option<ArtistRef>,
+    pub description: Option<String>,
+    /// Only known for playlists of the signed in user
+    pub privacy: Option<Privacy>,
+    pub track_count: Option<usize>,
+    /// In an "n hours, n minutes" format
+    pub duration: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub tracks: Vec<PlaylistItem>,
+}
+
+impl Playlist {
+    /// Parse the first page of a playlist, with the token for the rest of its tracks
+    pub(crate) fn parse(res: Value, id: &str) -> Result<(Self, Option<ContinuationToken>), ResponseParseError> {
+        // playlists of the signed in user come with an editable header wrapped around the usual one
+        let editable = res.pointer(EDITABLE_PLAYLIST_HEADER);
+        let header = match editable {
+            Some(editable) => value_from_json(editable, EDITABLE_HEADER)?,
+            None => value_from_json(&res, PLAYLIST_HEADER)?,
+        };
+        // such as "1.2K views • 50 songs • 3 hours, 2 minutes", where only the song count is always present
+        let (duration, counts): (Vec<&str>, Vec<&str>) = iter_from_json(header, HEADER_SECOND_SUBTITLE).into_iter().flatten()
+            .filter_map(|run| run.pointer(RUN_TEXT)?.as_str())
+            .filter(|text| *text != " • ")
+            .partition(|text| text.contains("hour") || text.contains("minute"));
+        let (tracks, next) = PlaylistItem::parse_page(&res)?;
+
+        Ok((Playlist {
+            id: id.to_string(),
+            title: string_from_json(header, HEADER_TITLE)?,
+            author: iter_from_json(header, HEADER_STRAPLINE).ok()
+                .and_then(|runs| ArtistRef::parse_runs(runs).into_iter().next()),
+            description: runs_text_from_json(header, HEADER_DESCRIPTION).ok(),
+            privacy: editable.and_then(|it| Privacy::parse(it.pointer(EDITABLE_HEADER_PRIVACY)?.as_str()?)),
+            // the views come before the song count
+            track_count: counts.iter().rev().find_map(|it| Some(count_from_text(it)? as usize)),
+            duration: duration.first().map(|it| it.to_string()),
+            thumbnails: thumbnails_from_json(header, HEADER_THUMBS).unwrap_or_default(),
+            tracks,
+        }, next))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Privacy {
+    Public,
+    Unlisted,
+    Private,
+}
+
+impl Privacy {
+    fn parse(privacy: &str) -> Option<Self> {
+        match privacy {
+            "PUBLIC" => Some(Privacy::Public),
+            "UNLISTED" => Some(Privacy::Unlisted),
+            "PRIVATE" => Some(Privacy::Private),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlaylistItem {
+    pub title: String,
+    /// Missing for deleted videos
+    pub video_id: Option<String>,
+    /// Identifies this occurrence of the video in the playlist, which can contain one video multiple times
+    pub set_video_id: Option<String>,
+    pub artists: Vec<ArtistRef>,
+    pub album: Option<AlbumRef>,
+    pub duration: Option<Duration>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub video_type: Option<MusicVideoType>,
+    /// Whether the item can be played, unavailable items are greyed out on the playlist's page
+    pub available: bool,
+}
+
+impl PlaylistItem {
+    pub(crate) fn parse(item: &Value) -> Option<Self> {
+        Some(PlaylistItem {
+            title: string_from_json(item, SONG_NAME).ok()?,
+            video_id: string_from_json(item, SONG_ID).ok(),
+            set_video_id: string_from_json(item, PLAYLIST_ITEM_SET_VIDEO_ID).ok(),
+            artists: iter_from_json(item, ALBUM_TRACK_ARTISTS).map(ArtistRef::parse_runs).unwrap_or_default(),
+            album: column_runs(item)
+                .find(|run| run.pointer(RUN_PAGE_TYPE).and_then(Value::as_str) == Some("MUSIC_PAGE_TYPE_ALBUM"))
+                .and_then(AlbumRef::parse),
+            duration: string_from_json(item, ALBUM_TRACK_DURATION).ok().and_then(|it| duration_from_text(&it)),
+            thumbnails: thumbnails_from_json(item, SONG_THUMBS).unwrap_or_default(),
+            video_type: item.pointer(SONG_WATCH).and_then(MusicVideoType::parse),
+            available: item.pointer(ALBUM_TRACK_DISPLAY_POLICY).and_then(Value::as_str)
+                != Some("MUSIC_ITEM_RENDERER_DISPLAY_POLICY_GREY_OUT"),
+        })
+    }
+
+    /// Parse the items of either the first page or a continuation
+    pub(crate) fn parse_page(res: &Value) -> Result<Continued<Self>, ResponseParseError> {
+        let (items, next) = shelf_page(res, PLAYLIST_SHELF, CONTINUED_PLAYLIST_SHELF, SHELF_ITEMS)?;
+        Ok((items.into_iter().filter_map(Self::parse).collect(), next))
+    }
+
+    pub(crate) fn parse_continuation(res: Value) -> Result<Continued<Self>, ResponseParseError> {
+        Self::parse_page(&res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::parse::fixtures::{column, text_column};
+
+    #[test]
+    fn playlist_with_continuation() {
+        let item = json!({"musicResponsiveListItemRenderer": {
+            "flexColumns": [
+                column(json!([{"text": "Sonne", "navigationEndpoint": {"watchEndpoint": {"videoId": "KUZ7jG7BKE8"}}}])),
+                column(json!([{"text": "Rammstein", "navigationEndpoint": {"browseEndpoint": {"browseId": "UCs6GGpd9zvsYghuYe0VDFUQ"}}}])),
+                column(json!([{"text": "Mutter", "navigationEndpoint": {"browseEndpoint": {
+                    "browseId": "MPREb_KtV3PgMEVJL",
+                    "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": "MUSIC_PAGE_TYPE_ALBUM"}},
+                }}}])),
+            ],
+            "fixedColumns": [{"musicResponsiveListItemFixedColumnRenderer": {"text": {"runs": [{"text": "4:32"}]}}}],
+            "playlistItemData": {"videoId": "KUZ7jG7BKE8", "playlistSetVideoId": "56B44F6D10557CC6"},
+        }});
+        let res = json!({"contents": {"twoColumnBrowseResultsRenderer": {
+            "tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {"contents": [{"musicResponsiveHeaderRenderer": {
+                "title": {"runs": [{"text": "Presenting Rammstein"}]},
+                "straplineTextOne": {"runs": [{"text": "YouTube Music"}]},
+                "secondSubtitle": {"runs": [{"text": "1.2K views"}, {"text": " • "}, {"text": "2,001 songs"}, {"text": " • "}, {"text": "5+ hours"}]},
+            }}]}}}}],
+            "secondaryContents": {"sectionListRenderer": {"contents": [{"musicPlaylistShelfRenderer": {"contents": [
+                item,
+                {"continuationItemRenderer": {"continuationEndpoint": {"continuationCommand": {"token": "next"}}}},
+            ]}}]}},
+        }}});
+        let (playlist, next) = Playlist::parse(res, "RDCLAK5uy_nP1U38z5TIWBgTTiXDBgO_3DaHSaotjbc").unwrap();
+
+        assert_eq!(playlist.title, "Presenting Rammstein");
+        assert_eq!(playlist.author.unwrap().name, "YouTube Music");
+        assert_eq!(playlist.privacy, None);
+        assert_eq!(playlist.track_count, Some(2001));
+        assert_eq!(playlist.duration.as_deref(), Some("5+ hours"));
+        assert_eq!(playlist.tracks.len(), 1);
+        let track = &playlist.tracks[0];
+        assert_eq!(track.title, "Sonne");
+        assert_eq!(track.set_video_id.as_deref(), Some("56B44F6D10557CC6"));
+        assert_eq!(track.artists[0].browse_id.as_deref(), Some("UCs6GGpd9zvsYghuYe0VDFUQ"));
+        assert_eq!(track.album.as_ref().unwrap().name, "Mutter");
+        assert_eq!(track.duration, Some(Duration::from_secs(272)));
+        assert_eq!(next, Some(ContinuationToken("next".to_string())));
+
+        let res = json!({"onResponseReceivedActions": [{"appendContinuationItemsAction": {"continuationItems": [
+            {"musicResponsiveListItemRenderer": {
+                "flexColumns": [text_column("Deleted video")],
+                "musicItemRendererDisplayPolicy": "MUSIC_ITEM_RENDERER_DISPLAY_POLICY_GREY_OUT",
+            }},
+        ]}}]});
+        let (items, next) = PlaylistItem::parse_continuation(res).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].video_id, None);
+        assert!(!items[0].available);
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn subtitle_with_song_count_only() {
+        let res = json!({"contents": {"twoColumnBrowseResultsRenderer": {
+            "tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {"contents": [{"musicResponsiveHeaderRenderer": {
+                "title": {"runs": [{"text": "Rammstein Live"}]},
+                "secondSubtitle": {"runs": [{"text": "50 songs"}]},
+            }}]}}}}],
+            "secondaryContents": {"sectionListRenderer": {"contents": [{"musicPlaylistShelfRenderer": {"contents": []}}]}},
+        }}});
+        let (playlist, _) = Playlist::parse(res, "PLrammsteinlive").unwrap();
+
+        assert_eq!(playlist.track_count, Some(50));
+        assert_eq!(playlist.duration, None);
+    }
+
+    #[test]
+    fn editable_playlist() {
+        let res = json!({"contents": {"twoColumnBrowseResultsRenderer": {
+            "tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {"contents": [{"musicEditablePlaylistDetailHeaderRenderer": {
+                "header": {"musicResponsiveHeaderRenderer": {
+                    "title": {"runs": [{"text": "My Rammstein"}]},
+                    "description": {"musicDescriptionShelfRenderer": {"description": {"runs": [
+                        {"text": "Live versions from "},
+                        {"text": "rammstein.de", "navigationEndpoint": {"urlEndpoint": {"url": "https://www.rammstein.de/"}}},
+                    ]}}},
+                }},
+                "editHeader": {"musicPlaylistEditHeaderRenderer": {"privacy": "UNLISTED"}},
+            }}]}}}}],
+            "secondaryContents": {"sectionListRenderer": {"contents": [{"musicPlaylistShelfRenderer": {"contents": []}}]}},
+        }}});
+        let (playlist, _) = Playlist::parse(res, "PLmyrammstein").unwrap();
+
+        assert_eq!(playlist.title, "My Rammstein");
+        assert_eq!(playlist.description.as_deref(), Some("Live versions from rammstein.de"));
+        assert_eq!(playlist.privacy, Some(Privacy::Unlisted));
+    }
+}
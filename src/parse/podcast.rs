@@ -0,0 +1,266 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use super::*;
+
+/// A link to a podcast, as found in search results
+#[derive(Debug, Clone)]
+pub struct PodcastRef {
+    pub title: String,
+    /// Starting with `MPSP`
+    pub browse_id: String,
+    pub author: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl PodcastRef {
+    pub(crate) fn parse_search(res: Value) -> Result<SearchPage<Self>, ResponseParseError> {
+        SearchPage::parse(&res, Self::parse_item)
+    }
+
+    pub(crate) fn parse_search_continuation(res: Value) -> Result<SearchPage<Self>, ResponseParseError> {
+        SearchPage::parse_continuation(&res, Self::parse_item)
+    }
+
+    fn parse_item(item: &Value) -> Option<Self> {
+        // such as "Podcast • author", where the author can be missing or link to their channel
+        let subtitle: Vec<&Value> = iter_from_json(item, SEARCHED_PODCAST_SUBTITLE).ok()?.collect();
+        let author = subtitle.iter().find(|run| run.pointer(RUN_BROWSE_ID).is_some())
+            .or_else(|| subtitle.iter().skip_while(|run| run.pointer(RUN_TEXT).and_then(Value::as_str) != Some(" • ")).nth(1))
+            .and_then(|run| run.pointer(RUN_TEXT)?.as_str());
+
+        Some(PodcastRef {
+            title: string_from_json(item, SONG_NAME).ok()?,
+            browse_id: string_from_json(item, SEARCHED_ARTIST_ID).ok()?,
+            author: author.map(str::to_string),
+            thumbnails: thumbnails_from_json(item, SONG_THUMBS).unwrap_or_default(),
+        })
+    }
+
+    /// Request the podcast with all of its episodes
+    pub async fn request(&self, client: &Client) -> Result<Podcast, Box<dyn Error>> {
+        client.get_podcast(&self.browse_id).await
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Podcast {
+    pub browse_id: String,
+    pub title: String,
+    pub author: Option<ArtistRef>,
+    pub description: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+    /// Newest first
+    pub episodes: Vec<Episode>,
+}
+
+impl Podcast {
+    /// Parse the first page of a podcast, with the token for the rest of its episodes
+    pub(crate) fn parse(res: Value, browse_id: &str) -> Result<(Self, Option<ContinuationToken>), ResponseParseError> {
+        let header = value_from_json(&res, PLAYLIST_HEADER)?;
+        let (episodes, next) = Episode::parse_page(&res)?;
+
+        Ok((Podcast {
+            browse_id: browse_id.to_string(),
+            title: string_from_json(header, HEADER_TITLE)?,
+            author: iter_from_json(header, HEADER_STRAPLINE).ok()
+                .and_then(|runs| ArtistRef::parse_runs(runs).into_iter().next()),
+            description: runs_text_from_json(header, HEADER_DESCRIPTION).ok(),
+            thumbnails: thumbnails_from_json(header, HEADER_THUMBS).unwrap_or_default(),
+            episodes,
+        }, next))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Episode {
+    pub title: String,
+    pub video_id: String,
+    /// Starting with `MPED`
+    pub browse_id: Option<String>,
+    /// The podcast's author, only present on the episode's own page
+    pub author: Option<ArtistRef>,
+    /// Such as "Mar 15, 2024"
+    pub date: Option<String>,
+    /// Precise to minutes, missing if Youtube sent it in a language other than English
+    pub duration: Option<Duration>,
+    pub description: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl Episode {
+    /// Parse from an item of a podcast's episode list
+    pub(crate) fn parse(item: &Value) -> Option<Self> {
+        let item = item.pointer(EPISODE_ITEM)?;
+        Some(Episode {
+            title: string_from_json(item, EPISODE_TITLE).ok()?,
+            video_id: string_from_json(item, EPISODE_VIDEO_ID).ok()?,
+            browse_id: string_from_json(item, EPISODE_BROWSE_ID).ok(),
+            author: None,
+            date: string_from_json(item, EPISODE_DATE).ok(),
+            duration: string_from_json(item, EPISODE_DURATION).ok().as_deref().and_then(episode_duration),
+            description: runs_text_from_json(item, EPISODE_DESCRIPTION).ok(),
+            thumbnails: thumbnails_from_json(item, EPISODE_THUMBS).unwrap_or_default(),
+        })
+    }
+
+    /// Parse from the episode's own page
+    pub(crate) fn parse_page_header(res: Value, video_id: &str) -> Result<Self, ResponseParseError> {
+        let header = value_from_json(&res, PLAYLIST_HEADER)?;
+        Ok(Episode {
+            title: string_from_json(header, HEADER_TITLE)?,
+            video_id: video_id.to_string(),
+            browse_id: Some(format!("MPED{video_id}")),
+            author: iter_from_json(header, HEADER_STRAPLINE).ok()
+                .and_then(|runs| ArtistRef::parse_runs(runs).into_iter().next()),
+            // the date comes last, after the episode's type
+            date: iter_from_json(header, EPISODE_HEADER_SUBTITLE).ok()
+                .and_then(|mut runs| runs.next_back()?.pointer(RUN_TEXT)?.as_str())
+                .map(str::to_string),
+            duration: string_from_json(header, EPISODE_HEADER_DURATION).ok().as_deref().and_then(episode_duration),
+            description: runs_text_from_json(&res, EPISODE_PAGE_DESCRIPTION).ok(),
+            thumbnails: thumbnails_from_json(header, HEADER_THUMBS).unwrap_or_default(),
+        })
+    }
+
+    /// Parse the episodes of either the first page or a continuation
+    fn parse_page(res: &Value) -> Result<Continued<Self>, ResponseParseError> {
+        let (items, next) = shelf_page(res, PODCAST_SHELF, CONTINUED_SHELF, SHELF_ITEMS)?;
+        Ok((items.into_iter().filter_map(Self::parse).collect(), next))
+    }
+
+    pub(crate) fn parse_continuation(res: Value) -> Result<Continued<Self>, ResponseParseError> {
+        Self::parse_page(&res)
+    }
+}
+
+/// Read a duration from a text such as "58 min" or "1 hr 5 min"
+fn episode_duration(text: &str) -> Option<Duration> {
+    let mut words = text.split_whitespace().peekable();
+    words.peek()?;
+    let mut seconds = 0;
+    while let Some(number) = words.next() {
+        let number: u64 = number.parse().ok()?;
+        seconds += number * match words.next()? {
+            "hr" | "hrs" => 3600,
+            "min" | "mins" => 60,
+            "sec" | "secs" => 1,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn header(header: Value) -> Value {
+        json!({"tabs": [{"tabRenderer": {"content": {"sectionListRenderer": {"contents": [
+            {"musicResponsiveHeaderRenderer": header},
+        ]}}}}]})
+    }
+
+    #[test]
+    fn podcast_with_continuation() {
+        let episode = json!({"musicMultiRowListItemRenderer": {
+            "title": {"runs": [{"text": "Episode 12", "navigationEndpoint": {"browseEndpoint": {"browseId": "MPEDnJ1eUe8vDYk"}}}]},
+            "subtitle": {"runs": [{"text": "Mar 15, 2024"}]},
+            "description": {"runs": [{"text": "We talk about "}, {"text": "Rammstein"}]},
+            "onTap": {"watchEndpoint": {"videoId": "nJ1eUe8vDYk"}},
+            "playbackProgress": {"musicPlaybackProgressRenderer": {"durationText": {"runs": [{"text": "0 min"}, {"text": "58 min"}]}}},
+        }});
+        let mut res = json!({"contents": {"twoColumnBrowseResultsRenderer": header(json!({
+            "title": {"runs": [{"text": "Metal Talk"}]},
+            "straplineTextOne": {"runs": [{"text": "Metal Talk", "navigationEndpoint": {"browseEndpoint": {"browseId": "UCmetaltalk"}}}]},
+            "description": {"musicDescriptionShelfRenderer": {"description": {"runs": [
+                {"text": "A podcast about metal\n\nMore at "},
+                {"text": "metaltalk.example", "navigationEndpoint": {"urlEndpoint": {"url": "https://metaltalk.example/"}}},
+            ]}}},
+        }))}});
+        res["contents"]["twoColumnBrowseResultsRenderer"]["secondaryContents"] = json!({"sectionListRenderer": {"contents": [
+            {"musicShelfRenderer": {
+                "contents": [episode],
+                "continuations": [{"nextContinuationData": {"continuation": "older episodes"}}],
+            }},
+        ]}});
+        let (podcast, next) = Podcast::parse(res, "MPSPPLmetaltalk").unwrap();
+
+        assert_eq!(podcast.title, "Metal Talk");
+        assert_eq!(podcast.author.unwrap().browse_id.as_deref(), Some("UCmetaltalk"));
+        assert_eq!(podcast.description.as_deref(), Some("A podcast about metal\n\nMore at metaltalk.example"));
+        let episode = &podcast.episodes[0];
+        assert_eq!(episode.title, "Episode 12");
+        assert_eq!(episode.video_id, "nJ1eUe8vDYk");
+        assert_eq!(episode.browse_id.as_deref(), Some("MPEDnJ1eUe8vDYk"));
+        assert_eq!(episode.date.as_deref(), Some("Mar 15, 2024"));
+        assert_eq!(episode.duration, Some(Duration::from_secs(58 * 60)));
+        assert_eq!(episode.description.as_deref(), Some("We talk about Rammstein"));
+        assert_eq!(next, Some(ContinuationToken("older episodes".to_string())));
+
+        let res = json!({"continuationContents": {"musicShelfContinuation": {"contents": []}}});
+        let (episodes, next) = Episode::parse_continuation(res).unwrap();
+        assert!(episodes.is_empty());
+        assert_eq!(next, None);
+    }
+
+    #[test]
+    fn episode_page() {
+        let mut res = json!({"contents": {"twoColumnBrowseResultsRenderer": header(json!({
+            "title": {"runs": [{"text": "Episode 12"}]},
+            "straplineTextOne": {"runs": [{"text": "Metal Talk"}]},
+            "subtitle": {"runs": [{"text": "Episode"}, {"text": " • "}, {"text": "Mar 15, 2024"}]},
+            "progress": {"musicPlaybackProgressRenderer": {"durationText": {"runs": [{"text": "0 min"}, {"text": "58 min"}]}}},
+        }))}});
+        res["contents"]["twoColumnBrowseResultsRenderer"]["secondaryContents"] = json!({"sectionListRenderer": {"contents": [
+            {"musicDescriptionShelfRenderer": {"description": {"runs": [{"text": "We talk about Rammstein"}]}}},
+        ]}});
+        let episode = Episode::parse_page_header(res, "nJ1eUe8vDYk").unwrap();
+
+        assert_eq!(episode.title, "Episode 12");
+        assert_eq!(episode.author.unwrap().name, "Metal Talk");
+        assert_eq!(episode.date.as_deref(), Some("Mar 15, 2024"));
+        assert_eq!(episode.duration, Some(Duration::from_secs(58 * 60)));
+        assert_eq!(episode.description.as_deref(), Some("We talk about Rammstein"));
+    }
+
+    #[test]
+    fn episode_durations() {
+        assert_eq!(episode_duration("58 min"), Some(Duration::from_secs(58 * 60)));
+        assert_eq!(episode_duration("1 hr 5 min"), Some(Duration::from_secs(3600 + 5 * 60)));
+        assert_eq!(episode_duration("2 hrs"), Some(Duration::from_secs(2 * 3600)));
+        assert_eq!(episode_duration("45 sec"), Some(Duration::from_secs(45)));
+        assert_eq!(episode_duration("58 Min."), None);
+        assert_eq!(episode_duration(""), None);
+    }
+
+    #[test]
+    fn search_continuation() {
+        let res = json!({"continuationContents": {"musicShelfContinuation": {
+            "contents": [{"musicResponsiveListItemRenderer": {
+                "flexColumns": [
+                    {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Metal Talk"}]}}},
+                    {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Podcast"}, {"text": " • "}, {"text": "Metal Talk Crew"}]}}},
+                ],
+                "navigationEndpoint": {"browseEndpoint": {"browseId": "MPSPPLmetaltalk"}},
+            }}, {"musicResponsiveListItemRenderer": {
+                "flexColumns": [
+                    {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Riffs"}]}}},
+                    {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Podcast"}]}}},
+                ],
+                "navigationEndpoint": {"browseEndpoint": {"browseId": "MPSPPLriffs"}},
+            }}],
+            "continuations": [{"nextContinuationData": {"continuation": "more podcasts"}}],
+        }}});
+        let page = PodcastRef::parse_search_continuation(res).unwrap();
+
+        assert_eq!(page.items[0].title, "Metal Talk");
+        assert_eq!(page.items[0].browse_id, "MPSPPLmetaltalk");
+        assert_eq!(page.items[0].author.as_deref(), Some("Metal Talk Crew"));
+        assert_eq!(page.items[1].author, None);
+        assert_eq!(page.next, Some(ContinuationToken("more podcasts".to_string())));
+    }
+}
@@ -1,26 +1,201 @@
 // TODO: try to automate updating this file
 pub const ARTIST_NAME: &str = "/header/musicImmersiveHeaderRenderer/title/runs/0/text";
-pub const ARTIST_DESCIPTION: &str = "/header/musicImmersiveHeaderRenderer/description/runs/0/text";
-pub const ARTIST_ALBUMS: &str = "/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/1/musicCarouselShelfRenderer/contents";
-pub const ARTIST_SINGLES: &str = "/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/2/musicCarouselShelfRenderer/contents";
+pub const ARTIST_DESCIPTION: &str = "/header/musicImmersiveHeaderRenderer/description/runs";
+pub const ARTIST_THUMBS: &str = "/header/musicImmersiveHeaderRenderer/thumbnail/musicThumbnailRenderer/thumbnail/thumbnails";
+pub const ARTIST_SUBSCRIBERS: &str = "/header/musicImmersiveHeaderRenderer/subscriptionButton/subscribeButtonRenderer/longSubscriberCountText/runs/0/text";
+pub const ARTIST_CHANNEL_ID: &str = "/header/musicImmersiveHeaderRenderer/subscriptionButton/subscribeButtonRenderer/channelId";
+pub const ARTIST_SHUFFLE_PLAYLIST_ID: &str = "/header/musicImmersiveHeaderRenderer/playButton/buttonRenderer/navigationEndpoint/watchEndpoint/playlistId";
+pub const ARTIST_RADIO_PLAYLIST_ID: &str = "/header/musicImmersiveHeaderRenderer/startRadioButton/buttonRenderer/navigationEndpoint/watchEndpoint/playlistId";
+pub const ARTIST_ABOUT_VIEWS: &str = "/musicDescriptionShelfRenderer/subheader/runs/0/text";
+pub const BROWSE_ID: &str = "/browseId";
+pub const BROWSE_PARAMS: &str = "/params";
+pub const BROWSE_PAGE_TYPE: &str = "/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType";
+pub const ARTIST_SECTIONS: &str = "/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents";
+pub const ARTIST_SONGS_SHELF: &str = "/musicShelfRenderer";
+pub const ARTIST_SONGS_BROWSE_ID: &str = "/bottomEndpoint/browseEndpoint/browseId";
+pub const SONG_NAME: &str = "/musicResponsiveListItemRenderer/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text";
+pub const SONG_ID: &str = "/musicResponsiveListItemRenderer/playlistItemData/videoId";
+pub const SONG_COLUMNS: &str = "/musicResponsiveListItemRenderer/flexColumns";
+pub const SONG_THUMBS: &str = "/musicResponsiveListItemRenderer/thumbnail/musicThumbnailRenderer/thumbnail/thumbnails";
+pub const SONG_PLAYS: &str = "/musicResponsiveListItemRenderer/flexColumns/2/musicResponsiveListItemFlexColumnRenderer/text/runs/0";
+pub const COLUMN_RUNS: &str = "/musicResponsiveListItemFlexColumnRenderer/text/runs";
+pub const RUN_BROWSE_ID: &str = "/navigationEndpoint/browseEndpoint/browseId";
+pub const RUN_PAGE_TYPE: &str = "/navigationEndpoint/browseEndpoint/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType";
+pub const RUN_ENDPOINT: &str = "/navigationEndpoint";
+pub const CAROUSEL_ITEMS: &str = "/musicCarouselShelfRenderer/contents";
+pub const CAROUSEL_TITLE: &str = "/musicCarouselShelfRenderer/header/musicCarouselShelfBasicHeaderRenderer/title/runs/0/text";
+pub const CAROUSEL_MORE_ENDPOINT: &str = "/musicCarouselShelfRenderer/header/musicCarouselShelfBasicHeaderRenderer/moreContentButton/buttonRenderer/navigationEndpoint/browseEndpoint";
+pub const CAROUSEL_MORE_BROWSE_ID: &str = "/musicCarouselShelfRenderer/header/musicCarouselShelfBasicHeaderRenderer/moreContentButton/buttonRenderer/navigationEndpoint/browseEndpoint/browseId";
+pub const TWO_ROW_TITLE: &str = "/musicTwoRowItemRenderer/title/runs/0/text";
+pub const TWO_ROW_SUBTITLE: &str = "/musicTwoRowItemRenderer/subtitle/runs";
+pub const TWO_ROW_THUMBS: &str = "/musicTwoRowItemRenderer/thumbnailRenderer/musicThumbnailRenderer/thumbnail/thumbnails";
+pub const TWO_ROW_BROWSE_ID: &str = "/musicTwoRowItemRenderer/navigationEndpoint/browseEndpoint/browseId";
+pub const TWO_ROW_PAGE_TYPE: &str = "/musicTwoRowItemRenderer/navigationEndpoint/browseEndpoint/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType";
+pub const TWO_ROW_PLAYLIST_ID: &str = "/musicTwoRowItemRenderer/thumbnailOverlay/musicItemThumbnailOverlayRenderer/content/musicPlayButtonRenderer/playNavigationEndpoint/watchPlaylistEndpoint/playlistId";
+pub const TWO_ROW_WATCH: &str = "/musicTwoRowItemRenderer/navigationEndpoint/watchEndpoint";
+pub const SONG_WATCH: &str = "/musicResponsiveListItemRenderer/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/navigationEndpoint/watchEndpoint";
+pub const WATCH_VIDEO_ID: &str = "/videoId";
+pub const WATCH_VIDEO_TYPE: &str = "/watchEndpointMusicSupportedConfigs/watchEndpointMusicConfig/musicVideoType";
 pub const ARTIST_PRODUCT_NAME: &str = "/musicTwoRowItemRenderer/title/runs/0/text";
 pub const ARTIST_PRODUCT_ID: &str = "/musicTwoRowItemRenderer/title/runs/0/navigationEndpoint/browseEndpoint/browseId";
 pub const ARTIST_PRODUCT_THUMBS: &str = "/musicTwoRowItemRenderer/thumbnailRenderer/musicThumbnailRenderer/thumbnail/thumbnails";
-pub const ARTIST_ALBUM_YEAR: &str = "/musicTwoRowItemRenderer/subtitle/runs/2/text";
-pub const ARTIST_SINGLE_YEAR: &str = "/musicTwoRowItemRenderer/subtitle/runs/0/text";
 
 pub const ALBUM_NAME: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicResponsiveHeaderRenderer/title/runs/0/text";
 pub const ALBUM_YEAR: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicResponsiveHeaderRenderer/subtitle/runs/2/text";
+pub const ALBUM_TYPE: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicResponsiveHeaderRenderer/subtitle/runs/0/text";
+pub const ALBUM_ARTISTS: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicResponsiveHeaderRenderer/straplineTextOne/runs";
+pub const ALBUM_TRACK_COUNT: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicResponsiveHeaderRenderer/secondSubtitle/runs/0/text";
+pub const ALBUM_DURATION: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicResponsiveHeaderRenderer/secondSubtitle/runs/2/text";
+pub const ALBUM_DESCRIPTION: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicResponsiveHeaderRenderer/description/musicDescriptionShelfRenderer/description/runs";
+pub const RUN_URL: &str = "/navigationEndpoint/urlEndpoint/url";
+pub const ALBUM_URL: &str = "/microformat/microformatDataRenderer/urlCanonical";
+pub const ALBUM_TRACK_PLAYLIST_ID: &str = "/musicResponsiveListItemRenderer/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/navigationEndpoint/watchEndpoint/playlistId";
 pub const ALBUM_THUMBS: &str = "/background/musicThumbnailRenderer/thumbnail/thumbnails";
-pub const ALBUM_TRACKS: &str = "/contents/twoColumnBrowseResultsRenderer/secondaryContents/sectionListRenderer/contents/0/musicShelfRenderer/contents";
+pub const ALBUM_SECTIONS: &str = "/contents/twoColumnBrowseResultsRenderer/secondaryContents/sectionListRenderer/contents";
+pub const ALBUM_SECTION_TRACKS: &str = "/musicShelfRenderer/contents";
 pub const ALBUM_TRACK_NAME: &str = "/musicResponsiveListItemRenderer/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text";
 pub const ALBUM_TRACK_ID: &str = "/musicResponsiveListItemRenderer/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/navigationEndpoint/watchEndpoint/videoId";
+pub const ALBUM_TRACK_INDEX: &str = "/musicResponsiveListItemRenderer/index/runs/0/text";
+pub const ALBUM_TRACK_ARTISTS: &str = "/musicResponsiveListItemRenderer/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs";
+pub const ALBUM_TRACK_PLAYS: &str = "/musicResponsiveListItemRenderer/flexColumns/2/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text";
+pub const ALBUM_TRACK_DURATION: &str = "/musicResponsiveListItemRenderer/fixedColumns/0/musicResponsiveListItemFixedColumnRenderer/text/runs/0/text";
+pub const ALBUM_TRACK_BADGES: &str = "/musicResponsiveListItemRenderer/badges";
+pub const ALBUM_TRACK_DISPLAY_POLICY: &str = "/musicResponsiveListItemRenderer/musicItemRendererDisplayPolicy";
+pub const BADGE_ICON: &str = "/musicInlineBadgeRenderer/icon/iconType";
 
 pub const THUMBNAIL_URL: &str = "/url";
 pub const THUMBNAIL_HEIGHT: &str = "/height";
 pub const THUMBNAIL_WIDTH: &str = "/width";
 
-pub const SEARCHED_ARTISTS: &str = "/contents/tabbedSearchResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/1/musicShelfRenderer/contents";
+pub const SEARCH_SECTIONS: &str = "/contents/tabbedSearchResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents";
+pub const SEARCH_SECTION_SHELF: &str = "/musicShelfRenderer";
+pub const SEARCH_SECTION_MESSAGES: &str = "/itemSectionRenderer/contents";
+pub const SEARCH_CORRECTION: &str = "/showingResultsForRenderer";
+pub const SEARCH_CORRECTED_QUERY: &str = "/correctedQuery/runs";
+pub const SEARCH_ORIGINAL_QUERY: &str = "/originalQuery/runs";
+pub const SHELF_ITEMS: &str = "/contents";
+pub const SHELF_CONTINUATION: &str = "/continuations/0/nextContinuationData/continuation";
 pub const SEARCHED_ARTIST_NAME: &str = "/musicResponsiveListItemRenderer/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text";
 pub const SEARCHED_ARTIST_SUBS: &str = "/musicResponsiveListItemRenderer/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs/2/text";
 pub const SEARCHED_ARTIST_ID: &str = "/musicResponsiveListItemRenderer/navigationEndpoint/browseEndpoint/browseId";
+
+
+pub const CONTINUED_SHELF_ITEMS: &str = "/continuationContents/musicShelfContinuation/contents";
+pub const CONTINUED_SHELF_CONTINUATION: &str = "/continuationContents/musicShelfContinuation/continuations/0/nextContinuationData/continuation";
+
+pub const SUGGESTION_SECTIONS: &str = "/contents";
+pub const SUGGESTION_SECTION_ITEMS: &str = "/searchSuggestionsSectionRenderer/contents";
+pub const SUGGESTION_TEXT_RUNS: &str = "/searchSuggestionRenderer/suggestion/runs";
+pub const SUGGESTION_TEXT_QUERY: &str = "/searchSuggestionRenderer/navigationEndpoint/searchEndpoint/query";
+pub const SUGGESTION_ENTITY: &str = "/musicResponsiveListItemRenderer";
+pub const SUGGESTION_ENTITY_TITLE: &str = "/flexColumns/0/musicResponsiveListItemFlexColumnRenderer/text/runs/0/text";
+pub const SUGGESTION_ENTITY_SUBTITLE: &str = "/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs";
+pub const SUGGESTION_ENTITY_BROWSE_ID: &str = "/navigationEndpoint/browseEndpoint/browseId";
+pub const SUGGESTION_ENTITY_PAGE_TYPE: &str = "/navigationEndpoint/browseEndpoint/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType";
+pub const SUGGESTION_ENTITY_VIDEO_ID: &str = "/navigationEndpoint/watchEndpoint/videoId";
+pub const SUGGESTION_ENTITY_VIDEO_TYPE: &str = "/navigationEndpoint/watchEndpoint/watchEndpointMusicSupportedConfigs/watchEndpointMusicConfig/musicVideoType";
+pub const SUGGESTION_ENTITY_THUMBS: &str = "/thumbnail/musicThumbnailRenderer/thumbnail/thumbnails";
+
+pub const RUN_TEXT: &str = "/text";
+pub const RUN_BOLD: &str = "/bold";
+
+pub const PLAYLIST_SHELF: &str = "/contents/twoColumnBrowseResultsRenderer/secondaryContents/sectionListRenderer/contents/0/musicPlaylistShelfRenderer";
+pub const CONTINUED_PLAYLIST_SHELF: &str = "/continuationContents/musicPlaylistShelfContinuation";
+pub const APPENDED_CONTINUATION_ITEMS: &str = "/onResponseReceivedActions/0/appendContinuationItemsAction/continuationItems";
+pub const CONTINUATION_ITEM_TOKEN: &str = "/continuationItemRenderer/continuationEndpoint/continuationCommand/token";
+
+pub const DISCOGRAPHY_GRID: &str = "/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/gridRenderer";
+pub const CONTINUED_GRID: &str = "/continuationContents/gridContinuation";
+pub const GRID_ITEMS: &str = "/items";
+
+pub const PLAYLIST_HEADER: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicResponsiveHeaderRenderer";
+pub const EDITABLE_PLAYLIST_HEADER: &str = "/contents/twoColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer/contents/0/musicEditablePlaylistDetailHeaderRenderer";
+pub const EDITABLE_HEADER: &str = "/header/musicResponsiveHeaderRenderer";
+pub const EDITABLE_HEADER_PRIVACY: &str = "/editHeader/musicPlaylistEditHeaderRenderer/privacy";
+pub const HEADER_TITLE: &str = "/title/runs/0/text";
+pub const HEADER_STRAPLINE: &str = "/straplineTextOne/runs";
+pub const HEADER_SECOND_SUBTITLE: &str = "/secondSubtitle/runs";
+pub const HEADER_DESCRIPTION: &str = "/description/musicDescriptionShelfRenderer/description/runs";
+pub const HEADER_THUMBS: &str = "/thumbnail/musicThumbnailRenderer/thumbnail/thumbnails";
+pub const PLAYLIST_ITEM_SET_VIDEO_ID: &str = "/musicResponsiveListItemRenderer/playlistItemData/playlistSetVideoId";
+
+pub const WATCH_NEXT_TABS: &str = "/contents/singleColumnMusicWatchNextResultsRenderer/tabbedRenderer/watchNextTabbedResultsRenderer/tabs";
+pub const WATCH_NEXT_QUEUE: &str = "/contents/singleColumnMusicWatchNextResultsRenderer/tabbedRenderer/watchNextTabbedResultsRenderer/tabs/0/tabRenderer/content/musicQueueRenderer/content/playlistPanelRenderer";
+pub const CONTINUED_QUEUE: &str = "/continuationContents/playlistPanelContinuation";
+pub const QUEUE_PLAYLIST_ID: &str = "/playlistId";
+pub const QUEUE_RADIO_CONTINUATION: &str = "/continuations/0/nextRadioContinuationData/continuation";
+pub const TAB_BROWSE_ID: &str = "/tabRenderer/endpoint/browseEndpoint/browseId";
+pub const TAB_PAGE_TYPE: &str = "/tabRenderer/endpoint/browseEndpoint/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType";
+pub const QUEUE_ITEM: &str = "/playlistPanelVideoRenderer";
+pub const QUEUE_WRAPPED_ITEM: &str = "/playlistPanelVideoWrapperRenderer/primaryRenderer/playlistPanelVideoRenderer";
+pub const QUEUE_ITEM_ID: &str = "/videoId";
+pub const QUEUE_ITEM_TITLE: &str = "/title/runs/0/text";
+pub const QUEUE_ITEM_BYLINE: &str = "/longBylineText/runs";
+pub const QUEUE_ITEM_LENGTH: &str = "/lengthText/runs/0/text";
+pub const QUEUE_ITEM_THUMBS: &str = "/thumbnail/thumbnails";
+pub const QUEUE_ITEM_WATCH: &str = "/navigationEndpoint/watchEndpoint";
+pub const QUEUE_ITEM_SET_VIDEO_ID: &str = "/navigationEndpoint/watchEndpoint/playlistSetVideoId";
+pub const QUEUE_ITEM_BADGES: &str = "/badges";
+
+pub const TIMED_LYRICS: &str = "/contents/elementRenderer/newElement/type/componentType/model/timedLyricsModel/lyricsData";
+pub const TIMED_LYRICS_LINES: &str = "/timedLyricsData";
+pub const TIMED_LYRICS_LINE: &str = "/lyricLine";
+pub const TIMED_LYRICS_START: &str = "/cueRange/startTimeMilliseconds";
+pub const TIMED_LYRICS_SOURCE: &str = "/sourceMessage";
+pub const LYRICS_SHELF: &str = "/contents/sectionListRenderer/contents/0/musicDescriptionShelfRenderer";
+pub const LYRICS_TEXT: &str = "/description/runs";
+pub const LYRICS_SOURCE: &str = "/footer/runs/0/text";
+
+pub const PLAYER_DETAILS: &str = "/videoDetails";
+pub const PLAYER_VIDEO_ID: &str = "/videoId";
+pub const PLAYER_TITLE: &str = "/title";
+pub const PLAYER_AUTHOR: &str = "/author";
+pub const PLAYER_CHANNEL_ID: &str = "/channelId";
+pub const PLAYER_LENGTH: &str = "/lengthSeconds";
+pub const PLAYER_VIEWS: &str = "/viewCount";
+pub const PLAYER_KEYWORDS: &str = "/keywords";
+pub const PLAYER_THUMBS: &str = "/thumbnail/thumbnails";
+pub const PLAYER_VIDEO_TYPE: &str = "/musicVideoType";
+pub const PLAYABILITY_STATUS: &str = "/playabilityStatus/status";
+pub const PLAYABILITY_REASON: &str = "/playabilityStatus/reason";
+pub const ADAPTIVE_FORMATS: &str = "/streamingData/adaptiveFormats";
+pub const FORMAT_ITAG: &str = "/itag";
+pub const FORMAT_MIME_TYPE: &str = "/mimeType";
+pub const FORMAT_BITRATE: &str = "/bitrate";
+pub const FORMAT_SAMPLE_RATE: &str = "/audioSampleRate";
+
+pub const RELATED_SECTIONS: &str = "/contents/sectionListRenderer/contents";
+pub const DESCRIPTION_SHELF_TITLE: &str = "/musicDescriptionShelfRenderer/header/runs/0/text";
+pub const DESCRIPTION_SHELF_TEXT: &str = "/musicDescriptionShelfRenderer/description/runs";
+
+pub const BROWSE_SECTION_LIST: &str = "/contents/singleColumnBrowseResultsRenderer/tabs/0/tabRenderer/content/sectionListRenderer";
+pub const CONTINUED_SECTION_LIST: &str = "/continuationContents/sectionListContinuation";
+pub const GRID_SECTION_TITLE: &str = "/gridRenderer/header/gridHeaderRenderer/title/runs/0/text";
+pub const GRID_SECTION_ITEMS: &str = "/gridRenderer/items";
+
+pub const CHART_RANK: &str = "/musicResponsiveListItemRenderer/customIndexColumn/musicCustomIndexColumnRenderer/text/runs/0/text";
+pub const CHART_TREND: &str = "/musicResponsiveListItemRenderer/customIndexColumn/musicCustomIndexColumnRenderer/icon/iconType";
+pub const CHART_ARTIST_PAGE_TYPE: &str = "/musicResponsiveListItemRenderer/navigationEndpoint/browseEndpoint/browseEndpointContextSupportedConfigs/browseEndpointContextMusicConfig/pageType";
+pub const CHART_ARTIST_SUBS: &str = "/musicResponsiveListItemRenderer/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs";
+pub const CHART_COUNTRY: &str = "/musicShelfRenderer/subheaders/0/musicSideAlignedItemRenderer/startItems/0/musicSortFilterButtonRenderer/title/runs/0/text";
+pub const CHART_COUNTRY_OPTIONS: &str = "/frameworkUpdates/entityBatchUpdate/mutations";
+pub const CHART_COUNTRY_OPTION: &str = "/payload/musicFormBooleanChoice/opaqueToken";
+
+pub const NAVIGATION_BUTTON_TITLE: &str = "/musicNavigationButtonRenderer/buttonText/runs/0/text";
+pub const NAVIGATION_BUTTON_PARAMS: &str = "/musicNavigationButtonRenderer/clickCommand/browseEndpoint/params";
+pub const IMMERSIVE_CAROUSEL_ITEMS: &str = "/musicImmersiveCarouselShelfRenderer/contents";
+
+pub const SEARCHED_PODCAST_SUBTITLE: &str = "/musicResponsiveListItemRenderer/flexColumns/1/musicResponsiveListItemFlexColumnRenderer/text/runs";
+pub const PODCAST_SHELF: &str = "/contents/twoColumnBrowseResultsRenderer/secondaryContents/sectionListRenderer/contents/0/musicShelfRenderer";
+pub const CONTINUED_SHELF: &str = "/continuationContents/musicShelfContinuation";
+pub const EPISODE_ITEM: &str = "/musicMultiRowListItemRenderer";
+pub const EPISODE_TITLE: &str = "/title/runs/0/text";
+pub const EPISODE_BROWSE_ID: &str = "/title/runs/0/navigationEndpoint/browseEndpoint/browseId";
+pub const EPISODE_DATE: &str = "/subtitle/runs/0/text";
+pub const EPISODE_DURATION: &str = "/playbackProgress/musicPlaybackProgressRenderer/durationText/runs/1/text";
+pub const EPISODE_VIDEO_ID: &str = "/onTap/watchEndpoint/videoId";
+pub const EPISODE_DESCRIPTION: &str = "/description/runs";
+pub const EPISODE_THUMBS: &str = "/thumbnail/musicThumbnailRenderer/thumbnail/thumbnails";
+pub const EPISODE_HEADER_SUBTITLE: &str = "/subtitle/runs";
+pub const EPISODE_HEADER_DURATION: &str = "/progress/musicPlaybackProgressRenderer/durationText/runs/1/text";
+pub const EPISODE_PAGE_DESCRIPTION: &str = "/contents/twoColumnBrowseResultsRenderer/secondaryContents/sectionListRenderer/contents/0/musicDescriptionShelfRenderer/description/runs";
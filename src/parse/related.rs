@@ -0,0 +1,99 @@
+use serde_json::Value;
+
+use super::*;
+
+/// A section of a song's related content
+#[derive(Debug, Clone)]
+pub enum RelatedSection {
+    /// Such as "You might also like"
+    Songs { title: String, items: Vec<ArtistSong> },
+    /// Such as "Recommended playlists"
+    Playlists { title: String, items: Vec<PlaylistRef> },
+    /// Such as "Similar artists"
+    Artists { title: String, items: Vec<ArtistSearchResult> },
+    /// Such as "About the artist"
+    Description { title: String, text: String },
+}
+
+impl RelatedSection {
+    pub(crate) fn parse_all(res: Value) -> Result<Vec<Self>, ResponseParseError> {
+        Ok(iter_from_json(&res, RELATED_SECTIONS)?.filter_map(Self::parse).collect())
+    }
+
+    /// Tell the kind of a section by its items
+    fn parse(section: &Value) -> Option<Self> {
+        if let Some(text) = section.pointer(DESCRIPTION_SHELF_TEXT) {
+            return Some(RelatedSection::Description {
+                title: string_from_json(section, DESCRIPTION_SHELF_TITLE).unwrap_or_default(),
+                text: runs_text_from_json(text, "").ok()?,
+            });
+        }
+
+        let title = string_from_json(section, CAROUSEL_TITLE).ok()?;
+        let items: Vec<&Value> = iter_from_json(section, CAROUSEL_ITEMS).ok()?.collect();
+        let first = items.first()?;
+        if first.pointer(SONG_COLUMNS).is_some() {
+            return Some(RelatedSection::Songs {
+                title,
+                items: items.into_iter().filter_map(ArtistSong::parse).collect(),
+            });
+        }
+        match first.pointer(TWO_ROW_PAGE_TYPE)?.as_str()? {
+            "MUSIC_PAGE_TYPE_PLAYLIST" => Some(RelatedSection::Playlists {
+                title,
+                items: items.into_iter().filter_map(PlaylistRef::parse).collect(),
+            }),
+            "MUSIC_PAGE_TYPE_ARTIST" => Some(RelatedSection::Artists {
+                title,
+                items: items.into_iter().filter_map(ArtistSearchResult::parse_related).collect(),
+            }),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::parse::fixtures::{carousel, column, text_column, two_row};
+
+    #[test]
+    fn related_sections() {
+        let song = json!({"musicResponsiveListItemRenderer": {
+            "flexColumns": [
+                text_column("Engel"),
+                column(json!([
+                    {"text": "Rammstein", "navigationEndpoint": {"browseEndpoint": {"browseId": "UCs6GGpd9zvsYghuYe0VDFUQ"}}},
+                    {"text": " • "},
+                    {"text": "Sehnsucht"},
+                ])),
+            ],
+            "playlistItemData": {"videoId": "x2rQzv8OWEY"},
+        }});
+        let res = json!({"contents": {"sectionListRenderer": {"contents": [
+            carousel("You might also like", json!([song])),
+            carousel("Recommended playlists", json!([two_row("Neue Deutsche Härte", "YouTube Music", "VLRDCLAK5uy_ndh", "MUSIC_PAGE_TYPE_PLAYLIST")])),
+            carousel("Similar artists", json!([two_row("Lindemann", "1.2M subscribers", "UCaUnlJ6wF8qFlo4l7ZPlEUQ", "MUSIC_PAGE_TYPE_ARTIST")])),
+            {"musicDescriptionShelfRenderer": {
+                "header": {"runs": [{"text": "About the artist"}]},
+                "description": {"runs": [{"text": "Rammstein is a German band"}]},
+            }},
+        ]}}});
+        let sections = RelatedSection::parse_all(res).unwrap();
+
+        assert_eq!(sections.len(), 4);
+        let RelatedSection::Songs { title, items } = &sections[0] else { panic!("expected songs") };
+        assert_eq!(title, "You might also like");
+        assert_eq!(items[0].name, "Engel");
+        assert_eq!(items[0].artists.len(), 1);
+        let RelatedSection::Playlists { items, .. } = &sections[1] else { panic!("expected playlists") };
+        assert_eq!(items[0].playlist_id, "RDCLAK5uy_ndh");
+        let RelatedSection::Artists { items, .. } = &sections[2] else { panic!("expected artists") };
+        assert_eq!(items[0].name, "Lindemann");
+        let RelatedSection::Description { title, text } = &sections[3] else { panic!("expected a description") };
+        assert_eq!(title, "About the artist");
+        assert_eq!(text, "Rammstein is a German band");
+    }
+}
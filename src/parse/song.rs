@@ -0,0 +1,193 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use super::*;
+
+/// Details of a song or video from the player, without its streams
+#[derive(Debug, Clone)]
+pub struct SongDetails {
+    pub video_id: String,
+    /// Missing, like the other video details, when the song can't be played at all
+    pub title: Option<String>,
+    /// Name of the uploading channel, usually the artist
+    pub author: Option<String>,
+    pub channel_id: Option<String>,
+    /// Precise to seconds
+    pub duration: Option<Duration>,
+    pub views: Option<u64>,
+    pub keywords: Vec<String>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub video_type: Option<MusicVideoType>,
+    pub playability: Playability,
+    /// Audio and video formats the song can be streamed in
+    pub formats: Vec<AdaptiveFormat>,
+}
+
+impl SongDetails {
+    pub(crate) fn parse(res: Value, video_id: &str) -> Result<Self, ResponseParseError> {
+        // error responses only come with the playability status
+        let details = res.pointer(PLAYER_DETAILS).unwrap_or(&Value::Null);
+        let duration = match string_from_json(details, PLAYER_LENGTH) {
+            Ok(length) => Some(Duration::from_secs(length.parse().map_err(|_| {
+                ResponseParseError::BadValue(PLAYER_LENGTH.to_string(), Value::String(length.clone()))
+            })?)),
+            Err(_) => None,
+        };
+
+        Ok(SongDetails {
+            video_id: string_from_json(details, PLAYER_VIDEO_ID).unwrap_or_else(|_| video_id.to_string()),
+            title: string_from_json(details, PLAYER_TITLE).ok(),
+            author: string_from_json(details, PLAYER_AUTHOR).ok(),
+            channel_id: string_from_json(details, PLAYER_CHANNEL_ID).ok(),
+            duration,
+            views: string_from_json(details, PLAYER_VIEWS).ok().and_then(|it| it.parse().ok()),
+            keywords: iter_from_json(details, PLAYER_KEYWORDS).into_iter().flatten()
+                .filter_map(|keyword| Some(keyword.as_str()?.to_string()))
+                .collect(),
+            thumbnails: thumbnails_from_json(details, PLAYER_THUMBS).unwrap_or_default(),
+            video_type: details.pointer(PLAYER_VIDEO_TYPE).and_then(Value::as_str).map(MusicVideoType::from_name),
+            playability: Playability::parse(&res),
+            formats: iter_from_json(&res, ADAPTIVE_FORMATS).into_iter().flatten()
+                .filter_map(AdaptiveFormat::parse)
+                .collect(),
+        })
+    }
+}
+
+/// Whether a song can be played, with Youtube's explanation if it can't
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Playability {
+    pub status: PlayabilityStatus,
+    /// Such as "This video is not available in your country"
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlayabilityStatus {
+    Playable,
+    /// Only playable after signing in with an adult account
+    AgeRestricted,
+    /// Not available in the country of the request
+    RegionBlocked,
+    LoginRequired,
+    Unplayable,
+    Other(String),
+}
+
+impl Playability {
+    fn parse(res: &Value) -> Self {
+        let reason = string_from_json(res, PLAYABILITY_REASON).ok();
+        let mentions = |text: &str| reason.as_deref().is_some_and(|reason| reason.contains(text));
+        let status = match res.pointer(PLAYABILITY_STATUS).and_then(Value::as_str).unwrap_or("OK") {
+            "OK" => PlayabilityStatus::Playable,
+            "AGE_CHECK_REQUIRED" | "AGE_VERIFICATION_REQUIRED" => PlayabilityStatus::AgeRestricted,
+            // both age restricted and private videos ask for signing in, only differing in the reason.
+            // the reason is localized, so these two only get recognized with the client's language set to English
+            "LOGIN_REQUIRED" if mentions("age") => PlayabilityStatus::AgeRestricted,
+            "LOGIN_REQUIRED" => PlayabilityStatus::LoginRequired,
+            "UNPLAYABLE" if mentions("country") => PlayabilityStatus::RegionBlocked,
+            "UNPLAYABLE" => PlayabilityStatus::Unplayable,
+            other => PlayabilityStatus::Other(other.to_string()),
+        };
+        Playability { status, reason }
+    }
+
+    pub fn is_playable(&self) -> bool {
+        self.status == PlayabilityStatus::Playable
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdaptiveFormat {
+    pub itag: u64,
+    /// Such as `audio/webm; codecs="opus"`
+    pub mime_type: String,
+    /// In bits per second
+    pub bitrate: u64,
+    /// In hertz, only present for audio formats
+    pub audio_sample_rate: Option<u64>,
+}
+
+impl AdaptiveFormat {
+    fn parse(format: &Value) -> Option<Self> {
+        Some(AdaptiveFormat {
+            itag: format.pointer(FORMAT_ITAG)?.as_u64()?,
+            mime_type: string_from_json(format, FORMAT_MIME_TYPE).ok()?,
+            bitrate: format.pointer(FORMAT_BITRATE)?.as_u64()?,
+            // sent as a string
+            audio_sample_rate: string_from_json(format, FORMAT_SAMPLE_RATE).ok().and_then(|it| it.parse().ok()),
+        })
+    }
+
+    pub fn is_audio(&self) -> bool {
+        self.mime_type.starts_with("audio/")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn song_details() {
+        let res = json!({
+            "playabilityStatus": {"status": "OK", "playableInEmbed": true},
+            "streamingData": {"adaptiveFormats": [
+                {"itag": 137, "mimeType": "video/mp4; codecs=\"avc1.640028\"", "bitrate": 4000000, "width": 1920, "height": 1080},
+                {"itag": 251, "mimeType": "audio/webm; codecs=\"opus\"", "bitrate": 140000, "audioSampleRate": "48000"},
+            ]},
+            "videoDetails": {
+                "videoId": "KUZ7jG7BKE8",
+                "title": "Sonne",
+                "lengthSeconds": "272",
+                "keywords": ["Rammstein", "Mutter"],
+                "channelId": "UCYp3rk70ACGXQ4gFAiMr1SQ",
+                "viewCount": "77218467",
+                "author": "Rammstein",
+                "musicVideoType": "MUSIC_VIDEO_TYPE_ATV",
+            },
+        });
+        let song = SongDetails::parse(res, "KUZ7jG7BKE8").unwrap();
+
+        assert_eq!(song.title.as_deref(), Some("Sonne"));
+        assert_eq!(song.author.as_deref(), Some("Rammstein"));
+        assert_eq!(song.duration, Some(Duration::from_secs(272)));
+        assert_eq!(song.views, Some(77218467));
+        assert_eq!(song.keywords, vec!["Rammstein", "Mutter"]);
+        assert_eq!(song.video_type, Some(MusicVideoType::Atv));
+        assert!(song.playability.is_playable());
+        assert_eq!(song.formats.len(), 2);
+        assert!(!song.formats[0].is_audio());
+        assert_eq!(song.formats[1].audio_sample_rate, Some(48000));
+    }
+
+    #[test]
+    fn unplayable_song() {
+        let res = json!({
+            "playabilityStatus": {"status": "ERROR", "reason": "This video is unavailable"},
+        });
+        let song = SongDetails::parse(res, "aaaaaaaaaaa").unwrap();
+
+        assert_eq!(song.video_id, "aaaaaaaaaaa");
+        assert_eq!(song.title, None);
+        assert_eq!(song.duration, None);
+        assert!(song.formats.is_empty());
+        assert_eq!(song.playability.status, PlayabilityStatus::Other("ERROR".to_string()));
+        assert_eq!(song.playability.reason.as_deref(), Some("This video is unavailable"));
+    }
+
+    #[test]
+    fn playability() {
+        let status = |status: &str, reason: &str| Playability::parse(&json!({
+            "playabilityStatus": {"status": status, "reason": reason},
+        })).status;
+
+        assert_eq!(status("LOGIN_REQUIRED", "Sign in to confirm your age"), PlayabilityStatus::AgeRestricted);
+        assert_eq!(status("LOGIN_REQUIRED", "This video is private"), PlayabilityStatus::LoginRequired);
+        assert_eq!(status("UNPLAYABLE", "This video is not available in your country"), PlayabilityStatus::RegionBlocked);
+        assert_eq!(status("ERROR", "This video is unavailable"), PlayabilityStatus::Other("ERROR".to_string()));
+    }
+}
@@ -0,0 +1,157 @@
+use serde_json::Value;
+
+use super::*;
+
+/// Type-ahead suggestions for a partially typed query
+#[derive(Debug, Clone)]
+pub struct SearchSuggestions {
+    pub text: Vec<TextSuggestion>,
+    /// Artists, albums or songs matching the query directly
+    pub entities: Vec<EntitySuggestion>,
+}
+
+impl SearchSuggestions {
+    pub(crate) fn parse(res: Value) -> Result<Self, ResponseParseError> {
+        let mut suggestions = SearchSuggestions {
+            text: Vec::new(),
+            entities: Vec::new(),
+        };
+        // an empty input gives no sections at all
+        let Ok(sections) = iter_from_json(&res, SUGGESTION_SECTIONS) else {
+            return Ok(suggestions);
+        };
+
+        for item in sections.filter_map(|section| iter_from_json(section, SUGGESTION_SECTION_ITEMS).ok()).flatten() {
+            if let Some(text) = TextSuggestion::parse(item) {
+                suggestions.text.push(text);
+            } else if let Some(entity) = item.pointer(SUGGESTION_ENTITY).and_then(EntitySuggestion::parse) {
+                suggestions.entities.push(entity);
+            }
+        }
+        Ok(suggestions)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TextSuggestion {
+    /// The full suggested query
+    pub query: String,
+    /// The suggestion split into runs, with the part matching the input not in bold
+    pub runs: Vec<TextRun>,
+}
+
+impl TextSuggestion {
+    fn parse(item: &Value) -> Option<Self> {
+        let runs: Vec<TextRun> = iter_from_json(item, SUGGESTION_TEXT_RUNS).ok()?.filter_map(|run| {
+            Some(TextRun {
+                text: string_from_json(run, RUN_TEXT).ok()?,
+                bold: run.pointer(RUN_BOLD).and_then(Value::as_bool).unwrap_or(false),
+            })
+        }).collect();
+        Some(TextSuggestion {
+            query: string_from_json(item, SUGGESTION_TEXT_QUERY).ok()
+                .unwrap_or_else(|| runs.iter().map(|run| run.text.as_str()).collect()),
+            runs,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextRun {
+    pub text: String,
+    pub bold: bool,
+}
+
+#[derive(Debug, Clone)]
+pub struct EntitySuggestion {
+    pub kind: EntityKind,
+    pub title: String,
+    /// Details such as the type, the artist or the subscriber count
+    pub subtitle: Vec<String>,
+    /// Set for artists and albums
+    pub browse_id: Option<String>,
+    /// Set for songs and videos
+    pub video_id: Option<String>,
+    pub thumbnails: Vec<Thumbnail>,
+}
+
+impl EntitySuggestion {
+    fn parse(item: &Value) -> Option<Self> {
+        let browse_id = string_from_json(item, SUGGESTION_ENTITY_BROWSE_ID).ok();
+        let video_id = string_from_json(item, SUGGESTION_ENTITY_VIDEO_ID).ok();
+        let kind = match (&browse_id, &video_id) {
+            (Some(_), _) => match item.pointer(SUGGESTION_ENTITY_PAGE_TYPE).and_then(Value::as_str) {
+                Some("MUSIC_PAGE_TYPE_ARTIST") => EntityKind::Artist,
+                Some("MUSIC_PAGE_TYPE_ALBUM") => EntityKind::Album,
+                Some("MUSIC_PAGE_TYPE_PLAYLIST") => EntityKind::Playlist,
+                _ => EntityKind::Other,
+            },
+            (None, Some(_)) => match item.pointer(SUGGESTION_ENTITY_VIDEO_TYPE).and_then(Value::as_str) {
+                Some("MUSIC_VIDEO_TYPE_ATV") => EntityKind::Song,
+                _ => EntityKind::Video,
+            },
+            (None, None) => return None,
+        };
+
+        Some(EntitySuggestion {
+            kind,
+            title: string_from_json(item, SUGGESTION_ENTITY_TITLE).ok()?,
+            subtitle: iter_from_json(item, SUGGESTION_ENTITY_SUBTITLE).map(|runs| {
+                runs.filter_map(|run| string_from_json(run, RUN_TEXT).ok())
+                    .filter(|text| text != " • ")
+                    .collect()
+            }).unwrap_or_default(),
+            browse_id,
+            video_id,
+            thumbnails: thumbnails_from_json(item, SUGGESTION_ENTITY_THUMBS).unwrap_or_default(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityKind {
+    Artist,
+    Album,
+    Playlist,
+    Song,
+    Video,
+    Other,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn text_and_entities() {
+        let res = json!({"contents": [
+            {"searchSuggestionsSectionRenderer": {"contents": [
+                {"searchSuggestionRenderer": {
+                    "suggestion": {"runs": [{"text": "rammst"}, {"text": "ein", "bold": true}]},
+                    "navigationEndpoint": {"searchEndpoint": {"query": "rammstein"}},
+                }},
+            ]}},
+            {"searchSuggestionsSectionRenderer": {"contents": [
+                {"musicResponsiveListItemRenderer": {
+                    "flexColumns": [
+                        {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Rammstein"}]}}},
+                        {"musicResponsiveListItemFlexColumnRenderer": {"text": {"runs": [{"text": "Artist"}, {"text": " • "}, {"text": "8.17M subscribers"}]}}},
+                    ],
+                    "navigationEndpoint": {"browseEndpoint": {
+                        "browseId": "UCs6GGpd9zvsYghuYe0VDFUQ",
+                        "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": "MUSIC_PAGE_TYPE_ARTIST"}},
+                    }},
+                }},
+            ]}},
+        ]});
+        let suggestions = SearchSuggestions::parse(res).unwrap();
+
+        assert_eq!(suggestions.text[0].query, "rammstein");
+        assert_eq!(suggestions.text[0].runs[1], TextRun { text: "ein".to_string(), bold: true });
+        assert_eq!(suggestions.entities[0].kind, EntityKind::Artist);
+        assert_eq!(suggestions.entities[0].subtitle, ["Artist", "8.17M subscribers"]);
+        assert_eq!(suggestions.entities[0].browse_id.as_deref(), Some("UCs6GGpd9zvsYghuYe0VDFUQ"));
+    }
+}
@@ -0,0 +1,174 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+use super::*;
+
+/// The up-next queue of a song, as Youtube Music's player builds it
+#[derive(Debug, Clone)]
+pub struct WatchPlaylist {
+    pub playlist_id: Option<String>,
+    pub tracks: Vec<QueueItem>,
+    /// Browse id of the lyrics tab, missing for songs without lyrics
+    pub lyrics_browse_id: Option<String>,
+    /// Browse id of the related content tab
+    pub related_browse_id: Option<String>,
+    /// Token for requesting more of the queue, if there is more
+    pub next: Option<ContinuationToken>,
+}
+
+impl WatchPlaylist {
+    pub(crate) fn parse(res: Value) -> Result<Self, ResponseParseError> {
+        let tabs: Vec<&Value> = iter_from_json(&res, WATCH_NEXT_TABS)?.collect();
+        let tab_browse_id = |page_type: &str| tabs.iter().find(|tab| {
+            tab.pointer(TAB_PAGE_TYPE).and_then(Value::as_str) == Some(page_type)
+        }).and_then(|tab| string_from_json(tab, TAB_BROWSE_ID).ok());
+        let (tracks, next) = QueueItem::parse_page(&res)?;
+
+        Ok(WatchPlaylist {
+            playlist_id: res.pointer(WATCH_NEXT_QUEUE)
+                .and_then(|queue| string_from_json(queue, QUEUE_PLAYLIST_ID).ok()),
+            tracks,
+            lyrics_browse_id: tab_browse_id("MUSIC_PAGE_TYPE_TRACK_LYRICS"),
+            related_browse_id: tab_browse_id("MUSIC_PAGE_TYPE_TRACK_RELATED"),
+            next,
+        })
+    }
+}
+
+/// A song or video in a player queue
+#[derive(Debug, Clone)]
+pub struct QueueItem {
+    pub title: String,
+    pub video_id: String,
+    /// Identifies this occurrence of the video in the queue's playlist
+    pub set_video_id: Option<String>,
+    pub artists: Vec<ArtistRef>,
+    pub album: Option<AlbumRef>,
+    pub year: Option<String>,
+    pub duration: Option<Duration>,
+    pub thumbnails: Vec<Thumbnail>,
+    pub video_type: Option<MusicVideoType>,
+    pub explicit: bool,
+}
+
+impl QueueItem {
+    pub(crate) fn parse(item: &Value) -> Option<Self> {
+        // songs with a music video counterpart are wrapped together with it
+        let item = item.pointer(QUEUE_ITEM).or_else(|| item.pointer(QUEUE_WRAPPED_ITEM))?;
+        // such as "Artist • Album • 2001", where videos have views in place of the album
+        let byline: Vec<&Value> = iter_from_json(item, QUEUE_ITEM_BYLINE).into_iter().flatten().collect();
+        let artists_end = byline.iter()
+            .position(|run| run.pointer(RUN_TEXT).and_then(Value::as_str) == Some(" • "))
+            .unwrap_or(byline.len());
+
+        Some(QueueItem {
+            title: string_from_json(item, QUEUE_ITEM_TITLE).ok()?,
+            video_id: string_from_json(item, QUEUE_ITEM_ID).ok()?,
+            set_video_id: string_from_json(item, QUEUE_ITEM_SET_VIDEO_ID).ok(),
+            artists: ArtistRef::parse_runs(byline[..artists_end].iter().copied()),
+            album: byline.iter()
+                .find(|run| run.pointer(RUN_PAGE_TYPE).and_then(Value::as_str) == Some("MUSIC_PAGE_TYPE_ALBUM"))
+                .and_then(|run| AlbumRef::parse(run)),
+            year: byline.last()
+                .and_then(|run| run.pointer(RUN_TEXT)?.as_str())
+                .filter(|text| text.len() == 4 && text.chars().all(|char| char.is_ascii_digit()))
+                .map(str::to_string),
+            duration: string_from_json(item, QUEUE_ITEM_LENGTH).ok().and_then(|it| duration_from_text(&it)),
+            thumbnails: thumbnails_from_json(item, QUEUE_ITEM_THUMBS).unwrap_or_default(),
+            video_type: item.pointer(QUEUE_ITEM_WATCH).and_then(MusicVideoType::parse),
+            explicit: iter_from_json(item, QUEUE_ITEM_BADGES).into_iter().flatten()
+                .any(|badge| badge.pointer(BADGE_ICON).and_then(Value::as_str) == Some("MUSIC_EXPLICIT_BADGE")),
+        })
+    }
+
+    /// Parse the items of either the first response or a continuation
+    fn parse_page(res: &Value) -> Result<Continued<Self>, ResponseParseError> {
+        let queue = match res.pointer(CONTINUED_QUEUE) {
+            Some(it) => it,
+            None => value_from_json(res, WATCH_NEXT_QUEUE)?,
+        };
+        let items = iter_from_json(queue, SHELF_ITEMS)?.filter_map(Self::parse).collect();
+        // radios come with a continuation of their own, which never runs out
+        let next = continuation_from_json(queue, SHELF_CONTINUATION)
+            .or_else(|| continuation_from_json(queue, QUEUE_RADIO_CONTINUATION));
+        Ok((items, next))
+    }
+
+    pub(crate) fn parse_continuation(res: Value) -> Result<Continued<Self>, ResponseParseError> {
+        Self::parse_page(&res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn tab(page_type: &str, browse_id: &str) -> Value {
+        json!({"tabRenderer": {"endpoint": {"browseEndpoint": {
+            "browseId": browse_id,
+            "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": page_type}},
+        }}}})
+    }
+
+    #[test]
+    fn watch_playlist() {
+        let song = json!({
+            "videoId": "KUZ7jG7BKE8",
+            "title": {"runs": [{"text": "Sonne"}]},
+            "lengthText": {"runs": [{"text": "4:32"}]},
+            "longBylineText": {"runs": [
+                {"text": "Rammstein", "navigationEndpoint": {"browseEndpoint": {"browseId": "UCs6GGpd9zvsYghuYe0VDFUQ"}}},
+                {"text": " • "},
+                {"text": "Mutter", "navigationEndpoint": {"browseEndpoint": {
+                    "browseId": "MPREb_KtV3PgMEVJL",
+                    "browseEndpointContextSupportedConfigs": {"browseEndpointContextMusicConfig": {"pageType": "MUSIC_PAGE_TYPE_ALBUM"}},
+                }}},
+                {"text": " • "},
+                {"text": "2001"},
+            ]},
+            "navigationEndpoint": {"watchEndpoint": {
+                "videoId": "KUZ7jG7BKE8",
+                "playlistSetVideoId": "set",
+                "watchEndpointMusicSupportedConfigs": {"watchEndpointMusicConfig": {"musicVideoType": "MUSIC_VIDEO_TYPE_ATV"}},
+            }},
+        });
+        let res = json!({"contents": {"singleColumnMusicWatchNextResultsRenderer": {"tabbedRenderer": {"watchNextTabbedResultsRenderer": {"tabs": [
+            {"tabRenderer": {"content": {"musicQueueRenderer": {"content": {"playlistPanelRenderer": {
+                "playlistId": "RDAMVMKUZ7jG7BKE8",
+                "contents": [
+                    {"playlistPanelVideoRenderer": song},
+                    {"playlistPanelVideoWrapperRenderer": {"primaryRenderer": {"playlistPanelVideoRenderer": song}}},
+                    {"automixPreviewVideoRenderer": {}},
+                ],
+                "continuations": [{"nextRadioContinuationData": {"continuation": "radio"}}],
+            }}}}}},
+            tab("MUSIC_PAGE_TYPE_TRACK_LYRICS", "MPLYt_lyrics"),
+            tab("MUSIC_PAGE_TYPE_TRACK_RELATED", "MPTRt_related"),
+        ]}}}}});
+        let playlist = WatchPlaylist::parse(res).unwrap();
+
+        assert_eq!(playlist.playlist_id.as_deref(), Some("RDAMVMKUZ7jG7BKE8"));
+        assert_eq!(playlist.lyrics_browse_id.as_deref(), Some("MPLYt_lyrics"));
+        assert_eq!(playlist.related_browse_id.as_deref(), Some("MPTRt_related"));
+        assert_eq!(playlist.next, Some(ContinuationToken("radio".to_string())));
+        assert_eq!(playlist.tracks.len(), 2);
+        let track = &playlist.tracks[0];
+        assert_eq!(track.title, "Sonne");
+        assert_eq!(track.artists, vec![ArtistRef { name: "Rammstein".to_string(), browse_id: Some("UCs6GGpd9zvsYghuYe0VDFUQ".to_string()) }]);
+        assert_eq!(track.album.as_ref().unwrap().browse_id, "MPREb_KtV3PgMEVJL");
+        assert_eq!(track.year.as_deref(), Some("2001"));
+        assert_eq!(track.duration, Some(Duration::from_secs(272)));
+        assert_eq!(track.set_video_id.as_deref(), Some("set"));
+        assert_eq!(track.video_type, Some(MusicVideoType::Atv));
+
+        let res = json!({"continuationContents": {"playlistPanelContinuation": {
+            "contents": [{"playlistPanelVideoRenderer": song}],
+        }}});
+        let (items, next) = QueueItem::parse_continuation(res).unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(next, None);
+    }
+}
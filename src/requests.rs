@@ -3,10 +3,45 @@ use serde_json::{json, Value, Map};
 
 use crate::{config::YoutubeConfig, BASE_URL};
 
+const MOBILE_CLIENT_VERSION: &str = "7.21.50";
+
 pub(crate) async fn create_api_request(config: &YoutubeConfig, endpoint_name: &str, input_variables: Map<String, Value>) -> Result<Value, reqwest::Error> {
+    send_api_request(config, api_url(config, endpoint_name), input_variables).await
+}
+
+/// Request as Youtube Music's android app, which gets some data the web client doesn't, such as timed lyrics
+pub(crate) async fn create_mobile_api_request(config: &YoutubeConfig, endpoint_name: &str, mut input_variables: Map<String, Value>) -> Result<Value, reqwest::Error> {
+    let mut context = api_context(config);
+    if let Some(client) = context.get_mut("context").and_then(|it| it.get_mut("client")) {
+        client["clientName"] = json!("ANDROID_MUSIC");
+        client["clientVersion"] = json!(MOBILE_CLIENT_VERSION);
+    }
+    input_variables.extend(context);
+    send_api_request(config, api_url(config, endpoint_name), input_variables).await
+}
+
+/// Request the next part of a paginated response
+pub(crate) async fn create_continuation_request(config: &YoutubeConfig, endpoint_name: &str, continuation: &str) -> Result<Value, reqwest::Error> {
+    let mut url = api_url(config, endpoint_name);
+    url.query_pairs_mut()
+        .append_pair("ctoken", continuation)
+        .append_pair("continuation", continuation)
+        .append_pair("type", "next");
+    // newer continuations are only read from the body
+    let body_vars = json!({
+        "continuation": continuation,
+    }).as_object().unwrap().to_owned();
+
+    send_api_request(config, url, body_vars).await
+}
+
+fn api_url(config: &YoutubeConfig, endpoint_name: &str) -> Url {
     let mut url = Url::parse(BASE_URL).expect("invalid base url");
     url.set_path(format!("youtubei/{}/{}", config.innertube_api_version, endpoint_name).as_str());
+    url
+}
 
+async fn send_api_request(config: &YoutubeConfig, url: Url, input_variables: Map<String, Value>) -> Result<Value, reqwest::Error> {
     let mut body = Map::new();
     for item in api_context(config) {
         body.insert(item.0, item.1);
@@ -40,6 +75,23 @@ pub(crate) async fn create_api_request(config: &YoutubeConfig, endpoint_name: &s
     Ok(res)
 }
 
+/// Request a page of the web app, as a browser would
+pub(crate) async fn create_page_request(url: Url) -> Result<String, reqwest::Error> {
+    reqwest::Client::new().get(url)
+        .header("User-Agent", "Mozilla/5.0 (X11; Linux x86_64; rv:104.0) Gecko/20100101 Firefox/104.0")
+        .header("Accept", "text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,*//*;q=0.8")
+        .header("Accept-Language", "en-US,en;q=0.5")
+        .header("Upgrade-Insecure-Requests", "1")
+        .header("Sec-Fetch-Dest", "document")
+        .header("Sec-Fetch-Mode", "navigate")
+        .header("Sec-Fetch-Site", "none")
+        .header("Sec-Fetch-User", "?1")
+        .send()
+        .await?
+        .text()
+        .await
+}
+
 pub(crate) fn endpoint_context(type_name: &str, browse_id: &str) -> Map<String, Value> {
     json!({
         "browseEndpointContextSupportedConfigs": {
@@ -51,6 +103,25 @@ pub(crate) fn endpoint_context(type_name: &str, browse_id: &str) -> Map<String,
     }).as_object().unwrap().to_owned()
 }
 
+/// Body of a `next` request, which either starts at a video, a playlist or both
+pub(crate) fn watch_context(video_id: Option<&str>, playlist_id: Option<&str>, params: Option<&str>) -> Map<String, Value> {
+    let mut body_vars = json!({
+        "enablePersistentPlaylistPanel": true,
+        "isAudioOnly": true,
+        "tunerSettingValue": "AUTOMIX_SETTING_NORMAL",
+    }).as_object().unwrap().to_owned();
+    if let Some(video_id) = video_id {
+        body_vars.insert("videoId".to_string(), Value::String(video_id.to_string()));
+    }
+    if let Some(playlist_id) = playlist_id {
+        body_vars.insert("playlistId".to_string(), Value::String(playlist_id.to_string()));
+    }
+    if let Some(params) = params {
+        body_vars.insert("params".to_string(), Value::String(params.to_string()));
+    }
+    body_vars
+}
+
 pub(crate) fn api_context(config: &YoutubeConfig) -> Map<String, Value> {
     json!({
         "context": {
@@ -0,0 +1,148 @@
+use base64::{Engine, engine::general_purpose::STANDARD};
+
+/// A category of search results, as offered by the chips above Youtube Music's search results
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchCategory {
+    Songs,
+    Videos,
+    Albums,
+    Artists,
+    Playlists,
+    Profiles,
+    Podcasts,
+    Episodes,
+}
+
+impl SearchCategory {
+    /// Field number of the category inside the filter message
+    fn field(&self) -> u64 {
+        match self {
+            SearchCategory::Songs => 1,
+            SearchCategory::Videos => 2,
+            SearchCategory::Albums => 3,
+            SearchCategory::Artists => 4,
+            SearchCategory::Playlists => 5,
+            SearchCategory::Episodes => 9,
+            SearchCategory::Podcasts => 10,
+            SearchCategory::Profiles => 11,
+        }
+    }
+}
+
+/// Options of a search request, encoded into the `params` of the `search` endpoint
+///
+/// ```
+/// use youtube_music::{SearchFilter, SearchCategory};
+///
+/// let filter = SearchFilter::new(SearchCategory::Artists);
+/// assert_eq!(filter.params().unwrap(), "EgWKAQIgAWoKEAkQChADEAUQBA%3D%3D");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SearchFilter {
+    pub category: Option<SearchCategory>,
+    pub ignore_spelling: bool,
+}
+
+impl SearchFilter {
+    /// Only search for results of the given category
+    pub fn new(category: SearchCategory) -> Self {
+        Self {
+            category: Some(category),
+            ignore_spelling: false,
+        }
+    }
+
+    /// Search for the query as it was typed, without Youtube's spelling correction
+    pub fn ignore_spelling(mut self) -> Self {
+        self.ignore_spelling = true;
+        self
+    }
+
+    /// Encode the filter into the protobuf `params` string, if there is anything to filter
+    pub fn params(&self) -> Option<String> {
+        if self.category.is_none() && !self.ignore_spelling {
+            return None;
+        }
+
+        let mut params = Vec::new();
+        if let Some(category) = self.category {
+            let mut filter = Vec::new();
+            write_varint_field(&mut filter, category.field(), 1);
+            let mut filters = Vec::new();
+            write_bytes_field(&mut filters, 17, &filter);
+            write_bytes_field(&mut params, 2, &filters);
+        }
+        if self.ignore_spelling {
+            let mut spelling = Vec::new();
+            write_varint_field(&mut spelling, 1, 1);
+            write_bytes_field(&mut params, 8, &spelling);
+        }
+        if self.category.is_some() {
+            // the shelves Youtube Music's web client asks for when filtering
+            let mut shelves = Vec::new();
+            for shelf in [9, 10, 3, 5, 4] {
+                write_varint_field(&mut shelves, 2, shelf);
+            }
+            write_bytes_field(&mut params, 13, &shelves);
+        }
+
+        Some(STANDARD.encode(params).replace('=', "%3D"))
+    }
+}
+
+fn write_varint(buf: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        buf.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    buf.push(value as u8);
+}
+
+fn write_varint_field(buf: &mut Vec<u8>, field: u64, value: u64) {
+    write_varint(buf, field << 3);
+    write_varint(buf, value);
+}
+
+fn write_bytes_field(buf: &mut Vec<u8>, field: u64, bytes: &[u8]) {
+    write_varint(buf, field << 3 | 2);
+    write_varint(buf, bytes.len() as u64);
+    buf.extend_from_slice(bytes);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(category: SearchCategory) -> String {
+        SearchFilter::new(category).params().unwrap()
+    }
+
+    #[test]
+    fn no_filter() {
+        assert_eq!(SearchFilter::default().params(), None);
+    }
+
+    #[test]
+    fn categories() {
+        assert_eq!(params(SearchCategory::Songs), "EgWKAQIIAWoKEAkQChADEAUQBA%3D%3D");
+        assert_eq!(params(SearchCategory::Videos), "EgWKAQIQAWoKEAkQChADEAUQBA%3D%3D");
+        assert_eq!(params(SearchCategory::Albums), "EgWKAQIYAWoKEAkQChADEAUQBA%3D%3D");
+        assert_eq!(params(SearchCategory::Artists), "EgWKAQIgAWoKEAkQChADEAUQBA%3D%3D");
+        assert_eq!(params(SearchCategory::Playlists), "EgWKAQIoAWoKEAkQChADEAUQBA%3D%3D");
+        assert_eq!(params(SearchCategory::Episodes), "EgWKAQJIAWoKEAkQChADEAUQBA%3D%3D");
+        assert_eq!(params(SearchCategory::Podcasts), "EgWKAQJQAWoKEAkQChADEAUQBA%3D%3D");
+        assert_eq!(params(SearchCategory::Profiles), "EgWKAQJYAWoKEAkQChADEAUQBA%3D%3D");
+    }
+
+    #[test]
+    fn ignore_spelling() {
+        assert_eq!(
+            SearchFilter::new(SearchCategory::Artists).ignore_spelling().params().unwrap(),
+            "EgWKAQIgAUICCAFqChAJEAoQAxAFEAQ%3D",
+        );
+        assert_eq!(
+            SearchFilter::default().ignore_spelling().params().unwrap(),
+            "QgIIAQ%3D%3D",
+        );
+    }
+}
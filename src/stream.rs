@@ -0,0 +1,47 @@
+use std::{error::Error, future::Future};
+
+use futures::{stream, Stream, TryStreamExt};
+
+use crate::{ContinuationToken, Continued};
+
+/// Turn a paginated endpoint into a stream of its items
+///
+/// `request` is called with `None` for the first page and with the previous page's token afterwards,
+/// the next page is only requested once the consumer has pulled every item of the current one
+pub(crate) fn paginate<'a, T, F, Fut>(request: F) -> impl Stream<Item = Result<T, Box<dyn Error>>> + 'a
+where
+    T: 'a,
+    F: FnMut(Option<ContinuationToken>) -> Fut + 'a,
+    Fut: Future<Output = Result<Continued<T>, Box<dyn Error>>> + 'a,
+{
+    stream::try_unfold((request, Some(None)), |(mut request, state)| async move {
+        let Some(token) = state else {
+            return Ok::<_, Box<dyn Error>>(None);
+        };
+        let (items, next) = request(token).await?;
+        Ok(Some((
+            stream::iter(items.into_iter().map(Ok)),
+            (request, next.map(Some)),
+        )))
+    }).try_flatten()
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn follows_tokens_until_exhausted() {
+        let items: Vec<_> = paginate(|token: Option<ContinuationToken>| async move {
+            Ok(match token.as_ref().map(|it| it.0.as_str()) {
+                None => (vec![1, 2], Some(ContinuationToken("a".to_string()))),
+                Some("a") => (vec![3], Some(ContinuationToken("b".to_string()))),
+                _ => (vec![4, 5], None),
+            })
+        }).map(Result::unwrap).collect().await;
+
+        assert_eq!(items, [1, 2, 3, 4, 5]);
+    }
+}